@@ -0,0 +1,6 @@
+-- Before migrations existed this Table was recreated on every Boot,
+-- so running Deployments may already have it.
+CREATE TABLE IF NOT EXISTS entries (
+  name TEXT NOT NULL PRIMARY KEY,
+  score BIGINT NOT NULL
+);
@@ -0,0 +1,3 @@
+ALTER TABLE entries ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT now();
+
+CREATE INDEX entries_score_idx ON entries (score DESC);
@@ -0,0 +1,17 @@
+-- Every finished Game. `entries` keeps the best Run of each Player.
+CREATE TABLE runs (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    score BIGINT NOT NULL,
+    level INTEGER NOT NULL,
+    duration_secs INTEGER NOT NULL,
+    client_version TEXT NOT NULL,
+    submitted_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX runs_name_idx ON runs (name, submitted_at DESC);
+CREATE INDEX runs_submitted_at_idx ON runs (submitted_at);
+
+-- Level and Duration of earlier best Scores are unknown
+INSERT INTO runs (name, score, level, duration_secs, client_version, submitted_at)
+SELECT name, score, 0, 0, 'unknown', updated_at FROM entries;
@@ -0,0 +1,8 @@
+-- Owners of Names. Only the SHA-256 Hash of the secret Token is stored.
+-- Names that were used before Accounts existed can be claimed once.
+CREATE TABLE players (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    token_hash TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
@@ -0,0 +1,5 @@
+-- Runs may carry a Replay of the Game. `verified` marks Scores a Replay backed.
+ALTER TABLE runs ADD COLUMN replay JSONB;
+ALTER TABLE runs ADD COLUMN verified BOOLEAN NOT NULL DEFAULT false;
+
+ALTER TABLE entries ADD COLUMN verified BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,20 @@
+-- Hidden Entries stay stored but don't show up on any Leaderboard
+ALTER TABLE entries ADD COLUMN hidden BOOLEAN NOT NULL DEFAULT false;
+
+-- Banned Names can't submit Runs or rename themselves
+CREATE TABLE bans (
+    name TEXT PRIMARY KEY,
+    reason TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- Every Action taken through the Admin Routes
+CREATE TABLE audit_log (
+    id BIGSERIAL PRIMARY KEY,
+    action TEXT NOT NULL,
+    target TEXT NOT NULL,
+    detail TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX audit_log_created_at ON audit_log (created_at DESC);
@@ -0,0 +1,26 @@
+-- Every Mode and Difficulty has its own Leaderboards.
+-- Earlier Runs were all played in Classic on Normal.
+ALTER TABLE runs ADD COLUMN mode TEXT NOT NULL DEFAULT 'classic';
+ALTER TABLE runs ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'normal';
+
+DROP INDEX runs_submitted_at_idx;
+CREATE INDEX runs_board_idx ON runs (mode, difficulty, submitted_at);
+
+-- An Entry is the best Run of a Name on one Board
+ALTER TABLE entries ADD COLUMN mode TEXT NOT NULL DEFAULT 'classic';
+ALTER TABLE entries ADD COLUMN difficulty TEXT NOT NULL DEFAULT 'normal';
+ALTER TABLE entries ADD COLUMN level INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE entries ADD COLUMN client_version TEXT NOT NULL DEFAULT 'unknown';
+
+UPDATE entries SET level = best.level, client_version = best.client_version
+FROM (
+    SELECT DISTINCT ON (name) name, level, client_version FROM runs
+    ORDER BY name, score DESC, submitted_at
+) AS best
+WHERE entries.name = best.name;
+
+ALTER TABLE entries DROP CONSTRAINT entries_pkey;
+ALTER TABLE entries ADD PRIMARY KEY (name, mode, difficulty);
+
+DROP INDEX entries_score_idx;
+CREATE INDEX entries_board_idx ON entries (mode, difficulty, score DESC);
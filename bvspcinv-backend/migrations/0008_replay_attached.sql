@@ -0,0 +1,3 @@
+-- A Replay is only checked for Plausibility, so it doesn't verify a Score
+ALTER TABLE runs RENAME COLUMN verified TO replay_attached;
+ALTER TABLE entries RENAME COLUMN verified TO replay_attached;
@@ -0,0 +1,6 @@
+-- Replays were only checked for Plausibility, not re-simulated, so they are
+-- gone until the Backend can verify a Score with them
+ALTER TABLE runs DROP COLUMN replay;
+ALTER TABLE runs DROP COLUMN replay_attached;
+
+ALTER TABLE entries DROP COLUMN replay_attached;
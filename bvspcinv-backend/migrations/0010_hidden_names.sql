@@ -0,0 +1,10 @@
+-- A Name is hidden on every Board at once, including Boards it only
+-- reaches after it was hidden
+CREATE TABLE hidden_names (
+    name TEXT PRIMARY KEY,
+    hidden_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+INSERT INTO hidden_names (name) SELECT DISTINCT name FROM entries WHERE hidden;
+
+ALTER TABLE entries DROP COLUMN hidden;
@@ -0,0 +1,233 @@
+use crate::{auth, error::Error, limit::Allowed, store::Store, DEFAULT_LIMIT, MAX_LIMIT};
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+    serde::json::Json,
+    Request, State,
+};
+use utils::{validation, AuditRecord, Ban, ModeratedEntry, PlayerName};
+
+/// Hash of the Token that unlocks the Admin Routes.
+/// Without one they are disabled.
+pub struct AdminToken(pub Option<String>);
+
+/// A Request carrying the Admin Token
+pub struct Admin;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Admin {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let Outcome::Success(admin_token) = req.guard::<&State<AdminToken>>().await else {
+            return Outcome::Error((Status::InternalServerError, ()));
+        };
+        match (&admin_token.0, auth::bearer(req)) {
+            (Some(expected), Some(token)) if *expected == auth::hash(token) => {
+                Outcome::Success(Self)
+            }
+            _ => Outcome::Error((Status::Unauthorized, ())),
+        }
+    }
+}
+
+fn no_entry(name: &str) -> Error {
+    Error::NotFound(format!("{name} has no Entry"))
+}
+
+/// Records the Action once it succeeded
+pub async fn audit(
+    store: &Store,
+    action: &str,
+    target: &str,
+    detail: Option<&str>,
+) -> Result<(), Error> {
+    store.audit(action, target, detail).await?;
+    rocket::info!("Admin: {action} {target}");
+    Ok(())
+}
+
+/// Every Entry including hidden ones, best first
+#[utoipa::path(
+    get,
+    path = "/admin/entries",
+    params(
+        ("limit" = Option<i64>, Query, description = "At most 100, defaults to 10"),
+        ("offset" = Option<i64>, Query, description = "Entries to skip"),
+    ),
+    security(("admin" = [])),
+    responses((status = 200, body = [ModeratedEntry]), (status = 401, body = ErrorBody))
+)]
+#[rocket::get("/admin/entries?<limit>&<offset>")]
+pub async fn get_entries(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> Result<Json<Vec<ModeratedEntry>>, Error> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(0, MAX_LIMIT);
+    let offset = offset.unwrap_or(0).max(0);
+
+    Ok(Json(store.moderated(limit, offset).await?))
+}
+
+/// Takes the Entry off every Leaderboard
+#[utoipa::path(
+    post,
+    path = "/admin/entries/{name}/hide",
+    params(("name" = String, Path, description = "Name of the Entry")),
+    security(("admin" = [])),
+    responses((status = 200), (status = 401, body = ErrorBody), (status = 404, body = ErrorBody))
+)]
+#[rocket::post("/admin/entries/<name>/hide")]
+pub async fn hide(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    name: &str,
+) -> Result<(), Error> {
+    if !store.hide(name, true).await? {
+        return Err(no_entry(name));
+    }
+    audit(store, "hide", name, None).await
+}
+
+/// Puts a hidden Entry back on the Leaderboards
+#[utoipa::path(
+    post,
+    path = "/admin/entries/{name}/show",
+    params(("name" = String, Path, description = "Name of the Entry")),
+    security(("admin" = [])),
+    responses((status = 200), (status = 401, body = ErrorBody), (status = 404, body = ErrorBody))
+)]
+#[rocket::post("/admin/entries/<name>/show")]
+pub async fn show(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    name: &str,
+) -> Result<(), Error> {
+    if !store.hide(name, false).await? {
+        return Err(no_entry(name));
+    }
+    audit(store, "show", name, None).await
+}
+
+/// Deletes the Entry with all its Runs. The Account stays.
+#[utoipa::path(
+    delete,
+    path = "/admin/entries/{name}",
+    params(("name" = String, Path, description = "Name of the Entry")),
+    security(("admin" = [])),
+    responses((status = 200), (status = 401, body = ErrorBody), (status = 404, body = ErrorBody))
+)]
+#[rocket::delete("/admin/entries/<name>")]
+pub async fn delete(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    name: &str,
+) -> Result<(), Error> {
+    if !store.remove(name).await? {
+        return Err(no_entry(name));
+    }
+    audit(store, "delete", name, None).await
+}
+
+/// Replaces an offensive Name. The Player keeps their Token.
+#[utoipa::path(
+    post,
+    path = "/admin/entries/{name}/rename",
+    params(("name" = String, Path, description = "Name of the Entry")),
+    request_body = PlayerName,
+    security(("admin" = [])),
+    responses(
+        (status = 200),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+        (status = 409, description = "The new Name is taken", body = ErrorBody),
+        (status = 422, description = "The new Name is invalid", body = ErrorBody),
+    )
+)]
+#[rocket::post("/admin/entries/<name>/rename", data = "<input>")]
+pub async fn rename(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    name: &str,
+    input: Json<PlayerName>,
+) -> Result<(), Error> {
+    validation::name(&input.name)?;
+    if !store.rename(name, &input.name).await? {
+        return Err(no_entry(name));
+    }
+    audit(store, "rename", name, Some(&input.name)).await
+}
+
+/// Bans the Name from submitting and hides its Entry
+#[utoipa::path(
+    post,
+    path = "/admin/bans/{name}",
+    params(("name" = String, Path, description = "Name of the Player")),
+    request_body = Ban,
+    security(("admin" = [])),
+    responses((status = 200), (status = 401, body = ErrorBody))
+)]
+#[rocket::post("/admin/bans/<name>", data = "<input>")]
+pub async fn ban(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    name: &str,
+    input: Json<Ban>,
+) -> Result<(), Error> {
+    store.ban(name, &input.reason).await?;
+    audit(store, "ban", name, Some(&input.reason)).await
+}
+
+/// Lifts the Ban. A hidden Entry stays hidden.
+#[utoipa::path(
+    delete,
+    path = "/admin/bans/{name}",
+    params(("name" = String, Path, description = "Name of the Player")),
+    security(("admin" = [])),
+    responses((status = 200), (status = 401, body = ErrorBody), (status = 404, body = ErrorBody))
+)]
+#[rocket::delete("/admin/bans/<name>")]
+pub async fn unban(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    name: &str,
+) -> Result<(), Error> {
+    if !store.unban(name).await? {
+        return Err(Error::NotFound(format!("{name} isn't banned")));
+    }
+    audit(store, "unban", name, None).await
+}
+
+/// Every Moderation Action, newest first
+#[utoipa::path(
+    get,
+    path = "/admin/audit",
+    params(
+        ("limit" = Option<i64>, Query, description = "At most 100, defaults to 10"),
+        ("offset" = Option<i64>, Query, description = "Records to skip"),
+    ),
+    security(("admin" = [])),
+    responses((status = 200, body = [AuditRecord]), (status = 401, body = ErrorBody))
+)]
+#[rocket::get("/admin/audit?<limit>&<offset>")]
+pub async fn get_audit_log(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> Result<Json<Vec<AuditRecord>>, Error> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(0, MAX_LIMIT);
+    let offset = offset.unwrap_or(0).max(0);
+
+    Ok(Json(store.audit_log(limit, offset).await?))
+}
@@ -0,0 +1,92 @@
+// The `OpenApi` Derive iterates the Paths with `for_each`
+#![allow(clippy::needless_for_each)]
+
+use crate::{admin, backup, boards, events, limit::Allowed, players};
+use rocket::serde::json::Json;
+use utils::{
+    AuditRecord, Ban, Credentials, Difficulty, Entry, ErrorBody, ErrorKind, ExportedRun, Imported,
+    Mode, ModeratedEntry, OnConflict, Page, Period, PlayerName, PlayerRank, RankedEntry, Recorded,
+    Run, RunRecord,
+};
+use utoipa::{
+    openapi::{
+        security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+        OpenApi as Document,
+    },
+    Modify, OpenApi,
+};
+
+/// Base Path of the current API Version
+pub const V1: &str = "/api/v1";
+
+#[derive(OpenApi)]
+#[openapi(
+    servers((url = "/api/v1")),
+    paths(
+        crate::get_entries,
+        crate::get_ranked_scores,
+        crate::post_run,
+        events::events,
+        boards::get_board,
+        players::get_player,
+        players::get_runs,
+        players::register,
+        players::rename,
+        players::delete,
+        admin::get_entries,
+        admin::hide,
+        admin::show,
+        admin::delete,
+        admin::rename,
+        admin::ban,
+        admin::unban,
+        admin::get_audit_log,
+        backup::export,
+        backup::import,
+    ),
+    components(schemas(
+        AuditRecord,
+        Ban,
+        Credentials,
+        Difficulty,
+        Entry,
+        ErrorBody,
+        ErrorKind,
+        ExportedRun,
+        Imported,
+        Mode,
+        ModeratedEntry,
+        OnConflict,
+        Page,
+        Period,
+        PlayerName,
+        PlayerRank,
+        RankedEntry,
+        Recorded,
+        Run,
+        RunRecord,
+    )),
+    modifiers(&Token)
+)]
+struct ApiDoc;
+
+/// The Bearer Tokens of Players and Admins
+struct Token;
+
+impl Modify for Token {
+    fn modify(&self, openapi: &mut Document) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        // Issued on Registration
+        let token = HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build();
+        components.add_security_scheme("token", SecurityScheme::Http(token));
+        // Configured as `admin_token`
+        let admin = HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build();
+        components.add_security_scheme("admin", SecurityScheme::Http(admin));
+    }
+}
+
+/// The `OpenAPI` Document of this Version
+#[rocket::get("/openapi.json")]
+pub fn openapi(_allowed: Allowed) -> Json<Document> {
+    Json(ApiDoc::openapi())
+}
@@ -0,0 +1,59 @@
+use crate::store::Store;
+use rand::RngCore;
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+    Request, State,
+};
+use sha2::{Digest, Sha256};
+
+const TOKEN_BYTES: usize = 32;
+
+/// A new secret Token. Only the Player ever sees it.
+pub fn generate_token() -> String {
+    let mut bytes = [0; TOKEN_BYTES];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// What is stored instead of the Token
+pub fn hash(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// The Token of the `Authorization: Bearer <token>` Header
+pub fn bearer<'r>(req: &'r Request<'_>) -> Option<&'r str> {
+    req.headers()
+        .get_one("Authorization")
+        .and_then(|value| value.strip_prefix("Bearer "))
+}
+
+/// The Player authenticated by the `Authorization: Bearer <token>` Header
+#[derive(sqlx::FromRow)]
+pub struct Player {
+    pub id: i64,
+    pub name: String,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Player {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let Some(token) = bearer(req) else {
+            return Outcome::Error((Status::Unauthorized, ()));
+        };
+        let Outcome::Success(store) = req.guard::<&State<Store>>().await else {
+            return Outcome::Error((Status::InternalServerError, ()));
+        };
+
+        match store.player(&hash(token)).await {
+            Ok(Some(player)) => Outcome::Success(player),
+            Ok(None) => Outcome::Error((Status::Unauthorized, ())),
+            Err(e) => {
+                rocket::error!("{e}");
+                Outcome::Error((Status::InternalServerError, ()))
+            }
+        }
+    }
+}
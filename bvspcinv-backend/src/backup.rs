@@ -0,0 +1,162 @@
+use crate::{
+    admin::{self, Admin},
+    error::Error,
+    limit::Allowed,
+    store::Store,
+};
+use rocket::{
+    data::{Limits, ToByteUnit},
+    serde::json::Json,
+    Data, Responder, State,
+};
+use utils::{ExportedRun, Imported, OnConflict};
+
+/// Largest CSV Backup accepted unless the `csv` Limit says otherwise
+const CSV_LIMIT: u64 = 16 * 1024 * 1024;
+
+/// Every Run in the requested Format
+#[derive(Responder)]
+pub enum Backup {
+    Json(Json<Vec<ExportedRun>>),
+    #[response(content_type = "text/csv")]
+    Csv(String),
+}
+
+fn to_csv(runs: &[ExportedRun]) -> Result<String, Error> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for run in runs {
+        writer
+            .serialize(run)
+            .map_err(|e| Error::Internal(format!("Couldn't write CSV: {e}")))?;
+    }
+    let csv = writer
+        .into_inner()
+        .map_err(|e| Error::Internal(format!("Couldn't write CSV: {e}")))?;
+    String::from_utf8(csv).map_err(|e| Error::Internal(format!("Couldn't write CSV: {e}")))
+}
+
+fn from_csv(csv: &str) -> Result<Vec<ExportedRun>, Error> {
+    csv::Reader::from_reader(csv.as_bytes())
+        .deserialize()
+        .collect::<Result<_, _>>()
+        .map_err(|e| Error::Validation(format!("The CSV is malformed: {e}")))
+}
+
+/// Exports every Run, including those of hidden Entries
+#[utoipa::path(
+    get,
+    path = "/admin/export",
+    params(("format" = Option<String>, Query, description = "`json` (default) or `csv`")),
+    security(("admin" = [])),
+    responses(
+        (status = 200, description = "JSON, or CSV with the same Fields as Columns", body = [ExportedRun]),
+        (status = 401, body = ErrorBody),
+        (status = 422, description = "Unknown Format", body = ErrorBody),
+    )
+)]
+#[rocket::get("/admin/export?<format>")]
+pub async fn export(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    format: Option<&str>,
+) -> Result<Backup, Error> {
+    let format = format.unwrap_or("json");
+    if !matches!(format, "json" | "csv") {
+        return Err(Error::Validation(format!(
+            "Unknown Format {format}, use json or csv"
+        )));
+    }
+
+    let runs = store.export().await?;
+    admin::audit(store, "export", "*", Some(format)).await?;
+    if format == "csv" {
+        to_csv(&runs).map(Backup::Csv)
+    } else {
+        Ok(Backup::Json(Json(runs)))
+    }
+}
+
+/// Imports a Backup and rebuilds the Entries of its Names
+async fn restore(
+    store: &Store,
+    on_conflict: Option<&str>,
+    runs: &[ExportedRun],
+) -> Result<Json<Imported>, Error> {
+    let on_conflict = match on_conflict {
+        Some(mode) => OnConflict::parse(mode).ok_or_else(|| {
+            Error::Validation(format!(
+                "Unknown Conflict Mode {mode}, use skip, overwrite or fail"
+            ))
+        })?,
+        None => OnConflict::default(),
+    };
+
+    let imported = store.import(runs, on_conflict).await?;
+    let detail = format!(
+        "{} Runs of {} Entries, {} skipped ({})",
+        imported.runs,
+        imported.entries,
+        imported.skipped,
+        on_conflict.as_str()
+    );
+    admin::audit(store, "import", "*", Some(&detail)).await?;
+    Ok(Json(imported))
+}
+
+/// Imports Runs exported as JSON.
+/// Backups are trusted, so the Runs aren't validated again.
+#[utoipa::path(
+    post,
+    path = "/admin/import",
+    params(("on_conflict" = Option<OnConflict>, Query, description = "Defaults to `skip`")),
+    request_body(content = [ExportedRun], description = "JSON or CSV as exported"),
+    security(("admin" = [])),
+    responses(
+        (status = 200, body = Imported),
+        (status = 401, body = ErrorBody),
+        (status = 409, description = "A Name already has an Entry and `on_conflict` is `fail`", body = ErrorBody),
+        (status = 413, description = "The Backup exceeds the `json` or `csv` Limit", body = ErrorBody),
+        (status = 422, description = "The Backup is malformed", body = ErrorBody),
+    )
+)]
+#[rocket::post("/admin/import?<on_conflict>", format = "json", data = "<input>")]
+pub async fn import(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    on_conflict: Option<&str>,
+    input: Json<Vec<ExportedRun>>,
+) -> Result<Json<Imported>, Error> {
+    restore(store, on_conflict, &input).await
+}
+
+/// [`import`] for Runs exported as CSV
+#[rocket::post(
+    "/admin/import?<on_conflict>",
+    format = "text/csv",
+    data = "<input>",
+    rank = 2
+)]
+pub async fn import_csv(
+    store: &State<Store>,
+    _allowed: Allowed,
+    _admin: Admin,
+    on_conflict: Option<&str>,
+    limits: &Limits,
+    input: Data<'_>,
+) -> Result<Json<Imported>, Error> {
+    let limit = limits.get("csv").unwrap_or_else(|| CSV_LIMIT.bytes());
+    let csv = input
+        .open(limit)
+        .into_string()
+        .await
+        .map_err(|e| Error::Validation(format!("The CSV isn't UTF-8: {e}")))?;
+    if !csv.is_complete() {
+        return Err(Error::TooLarge(format!(
+            "The CSV is larger than the Limit of {limit}"
+        )));
+    }
+
+    restore(store, on_conflict, &from_csv(&csv)?).await
+}
@@ -0,0 +1,93 @@
+use crate::{error::Error, limit::Allowed, store::Store, DEFAULT_LIMIT, MAX_LIMIT};
+use rocket::{serde::json::Json, State};
+use std::time::{SystemTime, UNIX_EPOCH};
+use utils::{Difficulty, Mode, Page, Period};
+
+const DAY: i64 = 24 * 60 * 60;
+
+/// Unix Timestamp the Period containing `now` started at, in UTC
+pub const fn start(period: Period, now: i64) -> Option<i64> {
+    let days = now.div_euclid(DAY);
+    let first_day = match period {
+        Period::Daily => days,
+        // The Epoch was a Thursday, Weeks start on Monday
+        Period::Weekly => days - (days + 3).rem_euclid(7),
+        Period::Monthly => days - (day_of_month(days) - 1),
+        Period::AllTime => return None,
+    };
+    Some(first_day * DAY)
+}
+
+/// Day of the Month, starting at 1, of the Days since the Epoch.
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+const fn day_of_month(days: i64) -> i64 {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    day_of_year - (153 * month + 2) / 5 + 1
+}
+
+/// The Board the `mode` and `difficulty` Query Parameters select.
+/// Older Clients send neither and get Classic on Normal.
+pub fn select(mode: Option<&str>, difficulty: Option<&str>) -> Result<(Mode, Difficulty), Error> {
+    let mode = match mode {
+        Some(mode) => {
+            Mode::parse(mode).ok_or_else(|| Error::Validation(format!("Unknown Mode {mode}")))?
+        }
+        None => Mode::default(),
+    };
+    let difficulty = match difficulty {
+        Some(difficulty) => Difficulty::parse(difficulty)
+            .ok_or_else(|| Error::Validation(format!("Unknown Difficulty {difficulty}")))?,
+        None => Difficulty::default(),
+    };
+    Ok((mode, difficulty))
+}
+
+/// The best Run of each Player within the current Period, best first
+#[utoipa::path(
+    get,
+    path = "/boards/{period}",
+    params(
+        ("period" = Period, Path, description = "Time Span of the Board"),
+        ("limit" = Option<i64>, Query, description = "At most 100, defaults to 10"),
+        ("offset" = Option<i64>, Query, description = "Entries to skip"),
+        ("mode" = Option<Mode>, Query, description = "Defaults to `classic`"),
+        ("difficulty" = Option<Difficulty>, Query, description = "Defaults to `normal`"),
+    ),
+    responses(
+        (status = 200, body = Page),
+        (status = 404, description = "Unknown Period", body = ErrorBody),
+        (status = 422, description = "Unknown Mode or Difficulty", body = ErrorBody),
+    )
+)]
+#[rocket::get("/boards/<period>?<limit>&<offset>&<mode>&<difficulty>")]
+pub async fn get_board(
+    store: &State<Store>,
+    _allowed: Allowed,
+    period: &str,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    mode: Option<&str>,
+    difficulty: Option<&str>,
+) -> Result<Json<Page>, Error> {
+    let Some(period) = Period::parse(period) else {
+        return Err(Error::NotFound(format!("There is no {period} Board")));
+    };
+    let (mode, difficulty) = select(mode, difficulty)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(0, MAX_LIMIT);
+    let offset = offset.unwrap_or(0).max(0);
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| Error::Internal(format!("The Clock is before 1970: {e}")))?
+        .as_secs();
+    let since = start(period, now.try_into().unwrap_or(i64::MAX));
+
+    let page = store.top(mode, difficulty, since, limit, offset).await?;
+    Ok(Json(page))
+}
@@ -0,0 +1,200 @@
+use crate::{limit, store::StoreError};
+use rocket::{
+    catch, catchers,
+    http::Status,
+    response::{self, status, Responder},
+    serde::json::Json,
+    Catcher, Request,
+};
+use std::time::Duration;
+use utils::{
+    validation::{NameError, RunError},
+    ErrorBody, ErrorKind,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    /// The submitted Data breaks a Rule
+    Validation(String),
+    /// The Name or Resource already belongs to someone else
+    Conflict(String),
+    /// The requested Resource doesn't exist
+    NotFound(String),
+    /// The Request Body exceeds the configured Limit
+    TooLarge(String),
+    /// The IP Address or Player is on the Ban List
+    Banned,
+    /// Too many Requests, try again after the Duration
+    RateLimited(Duration),
+    /// A Dependency like the Database can't be reached.
+    /// The Details are logged, but never sent to the Client.
+    Unavailable(String),
+    /// Something broke on our Side.
+    /// The Details are logged, but never sent to the Client.
+    Internal(String),
+}
+
+impl Error {
+    const fn status(&self) -> Status {
+        match self {
+            Self::Validation(_) => Status::UnprocessableEntity,
+            Self::Conflict(_) => Status::Conflict,
+            Self::NotFound(_) => Status::NotFound,
+            Self::TooLarge(_) => Status::PayloadTooLarge,
+            Self::Banned => Status::Forbidden,
+            Self::RateLimited(_) => Status::TooManyRequests,
+            Self::Unavailable(_) => Status::ServiceUnavailable,
+            Self::Internal(_) => Status::InternalServerError,
+        }
+    }
+
+    const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Validation(_) => ErrorKind::Validation,
+            Self::Conflict(_) => ErrorKind::Conflict,
+            Self::NotFound(_) => ErrorKind::NotFound,
+            Self::TooLarge(_) => ErrorKind::TooLarge,
+            Self::Banned => ErrorKind::Banned,
+            Self::RateLimited(_) => ErrorKind::RateLimited,
+            Self::Unavailable(_) => ErrorKind::Unavailable,
+            Self::Internal(_) => ErrorKind::Internal,
+        }
+    }
+}
+
+impl From<NameError> for Error {
+    fn from(value: NameError) -> Self {
+        Self::Validation(value.to_string())
+    }
+}
+
+impl From<RunError> for Error {
+    fn from(value: RunError) -> Self {
+        Self::Validation(value.to_string())
+    }
+}
+
+impl From<StoreError> for Error {
+    fn from(value: StoreError) -> Self {
+        match value {
+            StoreError::NameTaken(_) | StoreError::EntryExists(_) => {
+                Self::Conflict(value.to_string())
+            }
+            StoreError::Backend(message) => Self::Internal(message),
+        }
+    }
+}
+
+impl<'r> Responder<'r, 'static> for Error {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let status = self.status();
+        let error = self.kind();
+        // Clients can't do anything with less than a Second
+        let retry_after = match self {
+            Self::RateLimited(wait) => Some(wait.as_secs().max(1)),
+            _ => None,
+        };
+        let message = match self {
+            Self::Validation(message)
+            | Self::Conflict(message)
+            | Self::NotFound(message)
+            | Self::TooLarge(message) => message,
+            Self::Unavailable(details) => {
+                rocket::error!("{} {}: {details}", req.method(), req.uri());
+                "The Leaderboard is unavailable right now, try again later".into()
+            }
+            Self::Internal(details) => {
+                rocket::error!("{} {}: {details}", req.method(), req.uri());
+                "Something went wrong on our Side".into()
+            }
+            Self::Banned => "You are banned from the Leaderboard".into(),
+            Self::RateLimited(_) => format!(
+                "Too many Requests, try again in {} Seconds",
+                retry_after.unwrap_or_default()
+            ),
+        };
+
+        let mut response =
+            status::Custom(status, Json(ErrorBody { error, message })).respond_to(req)?;
+        if let Some(seconds) = retry_after {
+            response.set_raw_header("Retry-After", seconds.to_string());
+        }
+        Ok(response)
+    }
+}
+
+pub fn catchers() -> Vec<Catcher> {
+    catchers![
+        bad_request,
+        unauthorized,
+        forbidden,
+        not_found,
+        payload_too_large,
+        unprocessable_entity,
+        too_many_requests,
+        internal_server_error
+    ]
+}
+
+fn body(error: ErrorKind, message: &str) -> Json<ErrorBody> {
+    Json(ErrorBody {
+        error,
+        message: message.to_owned(),
+    })
+}
+
+#[catch(400)]
+fn bad_request() -> Json<ErrorBody> {
+    body(ErrorKind::BadRequest, "The Request is malformed")
+}
+
+#[catch(401)]
+fn unauthorized(req: &Request<'_>) -> Json<ErrorBody> {
+    let admin = req
+        .route()
+        .is_some_and(|route| route.uri.path().contains("/admin/"));
+    if admin {
+        body(ErrorKind::Unauthorized, "A valid Admin Token is required")
+    } else {
+        body(
+            ErrorKind::Unauthorized,
+            "A valid Player Token is required. Register first.",
+        )
+    }
+}
+
+#[catch(403)]
+fn forbidden() -> Json<ErrorBody> {
+    body(ErrorKind::Banned, "You are banned from the Leaderboard")
+}
+
+#[catch(404)]
+fn not_found() -> Json<ErrorBody> {
+    body(ErrorKind::NotFound, "Nothing was found here")
+}
+
+#[catch(413)]
+fn payload_too_large() -> Json<ErrorBody> {
+    body(
+        ErrorKind::TooLarge,
+        "The Request Body exceeds the configured Limit",
+    )
+}
+
+#[catch(422)]
+fn unprocessable_entity() -> Json<ErrorBody> {
+    body(
+        ErrorKind::Validation,
+        "The Request Body doesn't match the expected Format",
+    )
+}
+
+#[catch(429)]
+fn too_many_requests(req: &Request<'_>) -> Error {
+    Error::RateLimited(limit::retry_after(req))
+}
+
+#[catch(500)]
+fn internal_server_error() -> Json<ErrorBody> {
+    body(ErrorKind::Internal, "Something went wrong on our Side")
+}
@@ -0,0 +1,64 @@
+use crate::{limit::Allowed, store::Store};
+use rocket::{
+    response::stream::{Event, EventStream},
+    tokio::{
+        select,
+        sync::broadcast::{self, error::RecvError},
+    },
+    Shutdown, State,
+};
+use utils::{Difficulty, Mode, RankedEntry};
+
+/// Changes kept for Subscribers that fall behind
+const CAPACITY: usize = 64;
+
+/// Pushes Rank Changes to every connected Client
+pub struct Events(broadcast::Sender<RankedEntry>);
+
+impl Default for Events {
+    fn default() -> Self {
+        Self(broadcast::channel(CAPACITY).0)
+    }
+}
+
+impl Events {
+    /// Announces the new Rank of the Player on the Board.
+    /// The Run is already stored, so Failures are only logged.
+    pub async fn announce(&self, store: &Store, name: &str, mode: Mode, difficulty: Difficulty) {
+        match store.surrounding(name, mode, difficulty, 0).await {
+            Ok((_, ranked)) => {
+                for entry in ranked {
+                    // Without Subscribers there is nobody to tell
+                    self.0.send(entry).ok();
+                }
+            }
+            Err(e) => rocket::warn!("Couldn't announce Rank of {name}: {e}"),
+        }
+    }
+}
+
+/// Sends a `rank` Event with the new Position of a Player
+/// whenever they set a personal Best
+#[utoipa::path(
+    get,
+    path = "/events",
+    responses((status = 200, content_type = "text/event-stream", body = RankedEntry))
+)]
+#[rocket::get("/events")]
+pub fn events(events: &State<Events>, _allowed: Allowed, mut shutdown: Shutdown) -> EventStream![] {
+    let mut changes = events.0.subscribe();
+    EventStream! {
+        loop {
+            let entry = select! {
+                received = changes.recv() => match received {
+                    Ok(entry) => entry,
+                    Err(RecvError::Closed) => break,
+                    // Clients refetch the Board on the next Change anyway
+                    Err(RecvError::Lagged(_)) => continue,
+                },
+                () = &mut shutdown => break,
+            };
+            yield Event::json(&entry).event("rank");
+        }
+    }
+}
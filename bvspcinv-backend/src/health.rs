@@ -0,0 +1,18 @@
+use crate::{error::Error, store::Store};
+use rocket::State;
+
+/// The Process is up
+#[rocket::get("/health")]
+pub const fn health() -> &'static str {
+    "ok"
+}
+
+/// The Store can be reached, so Requests can be served
+#[rocket::get("/ready")]
+pub async fn ready(store: &State<Store>) -> Result<&'static str, Error> {
+    store
+        .ping()
+        .await
+        .map_err(|e| Error::Unavailable(e.to_string()))?;
+    Ok("ready")
+}
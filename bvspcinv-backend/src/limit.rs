@@ -0,0 +1,182 @@
+use crate::error::Error;
+use rocket::{
+    http::Status,
+    request::{FromRequest, Outcome},
+    Request, State,
+};
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, HashSet},
+    net::IpAddr,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+const WINDOW: Duration = Duration::from_mins(1);
+/// Finished Windows are forgotten once this many Keys are tracked
+const PRUNE_AT: usize = 10_000;
+
+/// The `abuse` Section of `Rocket.toml`
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Requests per Minute from one IP Address
+    pub requests_per_minute: u32,
+    /// Submissions per Minute of one Player
+    pub submissions_per_minute: u32,
+    pub banned_ips: HashSet<IpAddr>,
+    pub banned_players: HashSet<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            requests_per_minute: 120,
+            submissions_per_minute: 6,
+            banned_ips: HashSet::new(),
+            banned_players: HashSet::new(),
+        }
+    }
+}
+
+/// Counts Hits per Key in fixed Windows
+pub struct Limiter {
+    max: u32,
+    window: Duration,
+    hits: Mutex<HashMap<String, (Instant, u32)>>,
+}
+
+impl Limiter {
+    pub fn new(max: u32, window: Duration) -> Self {
+        Self {
+            max,
+            window,
+            hits: Mutex::default(),
+        }
+    }
+
+    /// Counts a Hit of the Key.
+    /// Once it is over the Limit, returns how long until the next Window.
+    pub fn hit(&self, key: &str) -> Result<(), Duration> {
+        self.hit_at(key, Instant::now())
+    }
+
+    fn hit_at(&self, key: &str, now: Instant) -> Result<(), Duration> {
+        let mut hits = self.hits.lock().expect("No Thread panics while counting");
+        if hits.len() >= PRUNE_AT {
+            hits.retain(|_, (start, _)| now.duration_since(*start) < self.window);
+        }
+
+        let (start, count) = hits.entry(key.to_owned()).or_insert((now, 0));
+        let elapsed = now.duration_since(*start);
+        if elapsed >= self.window {
+            (*start, *count) = (now, 0);
+        } else if *count >= self.max {
+            return Err(self.window.saturating_sub(elapsed));
+        }
+        *count += 1;
+        drop(hits);
+        Ok(())
+    }
+}
+
+/// Rate Limits and Ban List of the Backend
+pub struct Limits {
+    per_ip: Limiter,
+    per_player: Limiter,
+    banned_ips: HashSet<IpAddr>,
+    banned_players: HashSet<String>,
+}
+
+impl From<Config> for Limits {
+    fn from(config: Config) -> Self {
+        Self {
+            per_ip: Limiter::new(config.requests_per_minute, WINDOW),
+            per_player: Limiter::new(config.submissions_per_minute, WINDOW),
+            banned_ips: config.banned_ips,
+            banned_players: config.banned_players,
+        }
+    }
+}
+
+impl Limits {
+    /// Checks whether the Player may submit another Run
+    pub fn player(&self, name: &str) -> Result<(), Error> {
+        self.unbanned(name)?;
+        self.per_player.hit(name).map_err(Error::RateLimited)
+    }
+
+    /// Checks only the Ban List, for Actions that aren't Submissions
+    pub fn unbanned(&self, name: &str) -> Result<(), Error> {
+        if self.banned_players.contains(name) {
+            return Err(Error::Banned);
+        }
+        Ok(())
+    }
+}
+
+/// How long the Client has to wait, remembered for the 429 Catcher
+struct RetryAfter(Duration);
+
+pub fn retry_after(req: &Request<'_>) -> Duration {
+    req.local_cache(|| RetryAfter(WINDOW)).0
+}
+
+/// A Request from an IP Address that isn't banned or over its Limit
+pub struct Allowed;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Allowed {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let Outcome::Success(limits) = req.guard::<&State<Limits>>().await else {
+            return Outcome::Error((Status::InternalServerError, ()));
+        };
+        // Without an Address there is nothing to count against
+        let Some(ip) = req.client_ip() else {
+            return Outcome::Success(Self);
+        };
+
+        if limits.banned_ips.contains(&ip) {
+            return Outcome::Error((Status::Forbidden, ()));
+        }
+        match limits.per_ip.hit(&ip.to_string()) {
+            Ok(()) => Outcome::Success(Self),
+            Err(wait) => {
+                req.local_cache(|| RetryAfter(wait));
+                Outcome::Error((Status::TooManyRequests, ()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_within_window() {
+        let limiter = Limiter::new(2, WINDOW);
+        let now = Instant::now();
+
+        assert_eq!(limiter.hit_at("a", now), Ok(()));
+        assert_eq!(limiter.hit_at("a", now), Ok(()));
+        assert_eq!(
+            limiter.hit_at("a", now + Duration::from_secs(20)),
+            Err(Duration::from_secs(40))
+        );
+        // Other Keys have their own Count
+        assert_eq!(limiter.hit_at("b", now), Ok(()));
+    }
+
+    #[test]
+    fn resets_after_window() {
+        let limiter = Limiter::new(1, WINDOW);
+        let now = Instant::now();
+
+        assert_eq!(limiter.hit_at("a", now), Ok(()));
+        assert!(limiter.hit_at("a", now).is_err());
+        assert_eq!(limiter.hit_at("a", now + WINDOW), Ok(()));
+    }
+}
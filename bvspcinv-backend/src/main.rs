@@ -1,55 +1,278 @@
-use rocket::{routes, serde::json::Json, State};
-use shuttle_runtime::CustomError;
-use sqlx::{Executor, PgPool};
-use utils::Entry;
+use admin::AdminToken;
+use auth::Player;
+use error::Error;
+use events::Events;
+use limit::{Allowed, Limits};
+use metrics::Metrics;
+use rocket::{figment::Figment, routes, serde::json::Json, Build, Rocket, Route, State};
+#[cfg(not(feature = "shuttle"))]
+use std::env;
+use store::{Postgres, Store};
+use utils::{validation, Entry, Page, Recorded, Run};
 
-pub struct DB {
-    pool: PgPool,
-}
+mod admin;
+mod api;
+mod auth;
+mod backup;
+mod boards;
+mod error;
+mod events;
+mod health;
+mod limit;
+mod metrics;
+mod players;
+mod store;
+#[cfg(test)]
+mod tests;
+
+const DEFAULT_LIMIT: i64 = 10;
+const MAX_LIMIT: i64 = 100;
+/// Configuration Key of the Token that unlocks the Admin Routes
+const ADMIN_TOKEN_KEY: &str = "admin_token";
 
+#[cfg(not(feature = "shuttle"))]
+const DATABASE_URL_ENV: &str = "DATABASE_URL";
+#[cfg(not(feature = "shuttle"))]
+const PORT_ENV: &str = "PORT";
+/// Set to `1` to run without a Database
+#[cfg(not(feature = "shuttle"))]
+const MEMORY_STORE_ENV: &str = "BVSPCINV_MEMORY_STORE";
+
+#[cfg(feature = "shuttle")]
 #[shuttle_runtime::main]
-async fn rocket(#[shuttle_shared_db::Postgres] pool: PgPool) -> shuttle_rocket::ShuttleRocket {
-    pool.execute(include_str!("../schema.sql"))
-        .await
-        .map_err(CustomError::new)?;
+async fn shuttle(
+    #[shuttle_shared_db::Postgres] pool: sqlx::PgPool,
+    #[shuttle_runtime::Secrets] secrets: shuttle_runtime::SecretStore,
+) -> shuttle_rocket::ShuttleRocket {
+    use shuttle_runtime::CustomError;
 
-    let state = DB { pool };
-    let rocket = rocket::build()
-        .mount("/", routes![get_scores, post_scores])
-        .manage(state);
+    let mut figment = rocket::Config::figment();
+    if let Some(token) = secrets.get("ADMIN_TOKEN") {
+        figment = figment.merge((ADMIN_TOKEN_KEY, token));
+    }
 
+    let store = Postgres::migrate(pool).await.map_err(CustomError::new)?;
+    let rocket = build(Box::new(store), figment).map_err(CustomError::new)?;
     Ok(rocket.into())
 }
 
+/// Runs without Shuttle against the Postgres Database at `DATABASE_URL`.
+/// Keeping everything in Memory instead has to be asked for, so a missing
+/// `DATABASE_URL` can't lose every Score on the next Restart.
+/// The Port is taken from `PORT`, `ROCKET_PORT` or `Rocket.toml`.
+#[cfg(not(feature = "shuttle"))]
+#[rocket::main]
+async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let url = env::var(DATABASE_URL_ENV).ok();
+    let store: Store = match &url {
+        Some(url) => {
+            let pool = sqlx::PgPool::connect(url).await?;
+            Box::new(Postgres::migrate(pool).await?)
+        }
+        None if env::var(MEMORY_STORE_ENV).is_ok_and(|opt_in| opt_in == "1") => {
+            Box::<store::Memory>::default()
+        }
+        None => {
+            return Err(format!(
+                "{DATABASE_URL_ENV} isn't set. Set {MEMORY_STORE_ENV}=1 to keep Scores in Memory."
+            )
+            .into())
+        }
+    };
+
+    let mut figment = rocket::Config::figment();
+    if let Ok(port) = env::var(PORT_ENV) {
+        figment = figment.merge(("port", port.parse::<u16>()?));
+    }
+
+    let rocket = build(store, figment)?;
+    if url.is_none() {
+        rocket::warn!("Scores are kept in Memory and lost on Shutdown");
+    }
+    rocket.launch().await?;
+    Ok(())
+}
+
+/// Sets up every Route on top of the Store
+fn build(store: Store, figment: Figment) -> Result<Rocket<Build>, Box<rocket::figment::Error>> {
+    let rocket = rocket::custom(figment);
+    let limits = rocket
+        .figment()
+        .focus("abuse")
+        .extract::<limit::Config>()
+        .map_err(Box::new)?;
+    let admin_token = rocket
+        .figment()
+        .extract_inner::<String>(ADMIN_TOKEN_KEY)
+        .ok()
+        .map(|token| auth::hash(&token));
+
+    Ok(rocket
+        .mount(
+            api::V1,
+            routes![get_entries, post_run, events::events, api::openapi],
+        )
+        .mount(
+            api::V1,
+            routes![
+                admin::get_entries,
+                admin::hide,
+                admin::show,
+                admin::delete,
+                admin::rename,
+                admin::ban,
+                admin::unban,
+                admin::get_audit_log,
+                backup::export,
+                backup::import,
+                backup::import_csv
+            ],
+        )
+        .mount(api::V1, shared_routes())
+        // Unversioned Routes of Clients from before `/api/v1`
+        .mount("/", routes![get_scores, post_scores])
+        .mount("/", shared_routes())
+        .mount(
+            "/",
+            routes![health::health, health::ready, metrics::metrics],
+        )
+        .register("/", error::catchers())
+        .attach(metrics::Recorder)
+        .manage(store)
+        .manage(Limits::from(limits))
+        .manage(Events::default())
+        .manage(AdminToken(admin_token))
+        .manage(Metrics::default()))
+}
+
+/// Routes that are the same with and without Version Prefix
+fn shared_routes() -> Vec<Route> {
+    routes![
+        get_ranked_scores,
+        boards::get_board,
+        players::get_player,
+        players::get_runs,
+        players::register,
+        players::rename,
+        players::delete
+    ]
+}
+
+/// The best Entry of every Player on the Board, best first
+#[utoipa::path(
+    get,
+    path = "/entries",
+    params(
+        ("mode" = Option<Mode>, Query, description = "Defaults to `classic`"),
+        ("difficulty" = Option<Difficulty>, Query, description = "Defaults to `normal`"),
+    ),
+    responses(
+        (status = 200, body = [Entry]),
+        (status = 422, description = "Unknown Mode or Difficulty", body = ErrorBody),
+    )
+)]
+#[rocket::get("/entries?<mode>&<difficulty>")]
+async fn get_entries(
+    store: &State<Store>,
+    _allowed: Allowed,
+    mode: Option<&str>,
+    difficulty: Option<&str>,
+) -> Result<Json<Vec<Entry>>, Error> {
+    let (mode, difficulty) = boards::select(mode, difficulty)?;
+    let entries = store.entries(mode, difficulty).await?;
+    Ok(Json(entries))
+}
+
+/// Unversioned [`get_entries`] of the Classic Board on Normal
 #[rocket::get("/")]
-async fn get_scores(db: &State<DB>) -> Result<Json<Vec<Entry>>, String> {
-    let res = sqlx::query_as::<_, Entry>("SELECT * FROM entries;")
-        .fetch_all(&db.pool)
-        .await
-        .map_err(|e| e.to_string())?;
-    Ok(Json(res))
+async fn get_scores(store: &State<Store>, allowed: Allowed) -> Result<Json<Vec<Entry>>, Error> {
+    get_entries(store, allowed, None, None).await
 }
 
-#[rocket::post("/", data = "<input>")]
-async fn post_scores(db: &State<DB>, input: Json<Entry>) -> Result<(), String> {
-    let entry = sqlx::query_as::<_, Entry>("SELECT * FROM entries WHERE name = $1")
-        .bind(&input.name)
-        .fetch_optional(&db.pool)
-        .await
-        .map_err(|e| format!("Couldn't get User: {e}"))?;
-
-    if let Some(old_entry) = entry {
-        if old_entry.score >= input.score {
-            return Ok(());
-        }
+/// Ranked Entries, best first.
+/// `since` only considers Runs submitted after the given Unix Timestamp.
+#[utoipa::path(
+    get,
+    path = "/scores",
+    params(
+        ("limit" = Option<i64>, Query, description = "At most 100, defaults to 10"),
+        ("offset" = Option<i64>, Query, description = "Entries to skip"),
+        ("since" = Option<i64>, Query, description = "Unix Timestamp"),
+        ("mode" = Option<Mode>, Query, description = "Defaults to `classic`"),
+        ("difficulty" = Option<Difficulty>, Query, description = "Defaults to `normal`"),
+    ),
+    responses(
+        (status = 200, body = Page),
+        (status = 422, description = "Unknown Mode or Difficulty", body = ErrorBody),
+    )
+)]
+#[rocket::get("/scores?<limit>&<offset>&<since>&<mode>&<difficulty>")]
+async fn get_ranked_scores(
+    store: &State<Store>,
+    _allowed: Allowed,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    since: Option<i64>,
+    mode: Option<&str>,
+    difficulty: Option<&str>,
+) -> Result<Json<Page>, Error> {
+    let (mode, difficulty) = boards::select(mode, difficulty)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(0, MAX_LIMIT);
+    let offset = offset.unwrap_or(0).max(0);
+
+    let page = store.top(mode, difficulty, since, limit, offset).await?;
+    Ok(Json(page))
+}
+
+/// Stores the Run and raises the Player's best Score on its Board if it beats it.
+/// Tells whether the Run is a new personal Best and announces it if so.
+/// Runs always count for the authenticated Player, whatever Name they carry.
+#[utoipa::path(
+    post,
+    path = "/runs",
+    request_body = Run,
+    security(("token" = [])),
+    responses(
+        (status = 200, body = Recorded),
+        (status = 401, body = ErrorBody),
+        (status = 403, description = "The Player is banned", body = ErrorBody),
+        (status = 422, description = "The Run is invalid", body = ErrorBody),
+        (status = 429, description = "Too many Submissions", body = ErrorBody),
+    )
+)]
+#[rocket::post("/runs", data = "<input>")]
+async fn post_run(
+    store: &State<Store>,
+    limits: &State<Limits>,
+    events: &State<Events>,
+    _allowed: Allowed,
+    player: Player,
+    input: Json<Run>,
+) -> Result<Json<Recorded>, Error> {
+    limits.player(&player.name)?;
+    if store.banned(&player.name).await? {
+        return Err(Error::Banned);
     }
+    validation::run(&input)?;
 
-    sqlx::query("INSERT INTO entries (name, score) VALUES ($1, $2) ON CONFLICT (name) DO UPDATE SET name = $1, score = $2;")
-        .bind(&input.name)
-        .bind(input.score)
-        .execute(&db.pool)
-        .await
-        .map_err(|e| format!("Couldn't Insert or Update Entry: {e}"))?;
+    let recorded = store.insert_run(&player.name, &input).await?;
+    if recorded.personal_best {
+        events
+            .announce(store, &player.name, input.mode, input.difficulty)
+            .await;
+    }
+    Ok(Json(recorded))
+}
 
-    Ok(())
+/// Unversioned [`post_run`]
+#[rocket::post("/", data = "<input>")]
+async fn post_scores(
+    store: &State<Store>,
+    limits: &State<Limits>,
+    events: &State<Events>,
+    allowed: Allowed,
+    player: Player,
+    input: Json<Run>,
+) -> Result<Json<Recorded>, Error> {
+    post_run(store, limits, events, allowed, player, input).await
 }
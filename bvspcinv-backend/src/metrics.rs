@@ -0,0 +1,177 @@
+use crate::store::Store;
+use rocket::{
+    fairing::{Fairing, Info, Kind},
+    http::Status,
+    Data, Request, Response, State,
+};
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Upper Bounds of the Latency Histogram, in Seconds
+const BUCKETS: [f64; 9] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5];
+/// Routes that take Submissions
+const SUBMISSIONS: [&str; 2] = ["post_run", "post_scores"];
+
+#[derive(Default)]
+struct Latency {
+    /// Requests per Bucket, not cumulative
+    buckets: [u64; BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl Latency {
+    fn observe(&mut self, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        if let Some(bucket) = BUCKETS.iter().position(|&le| seconds <= le) {
+            self.buckets[bucket] += 1;
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+#[derive(Default)]
+struct Counts {
+    /// Keyed by Method, Route and Status
+    requests: BTreeMap<(String, String, u16), u64>,
+    /// Keyed by Method and Route
+    latencies: BTreeMap<(String, String), Latency>,
+    submissions_accepted: u64,
+    submissions_rejected: u64,
+}
+
+/// Request Counts and Latencies, collected by the [`Recorder`]
+#[derive(Default)]
+pub struct Metrics(Mutex<Counts>);
+
+impl Metrics {
+    fn record(&self, req: &Request<'_>, status: Status, duration: Duration) {
+        let method = req.method().as_str().to_owned();
+        // Only known Routes, so random Paths can't blow up the Series
+        let route = req
+            .route()
+            .map_or_else(|| "unmatched".into(), |route| route.uri.path().to_string());
+        let submission = req
+            .route()
+            .and_then(|route| route.name.as_deref())
+            .is_some_and(|name| SUBMISSIONS.contains(&name));
+
+        let mut counts = self.0.lock().expect("No Thread panics while counting");
+        *counts
+            .requests
+            .entry((method.clone(), route.clone(), status.code))
+            .or_default() += 1;
+        counts
+            .latencies
+            .entry((method, route))
+            .or_default()
+            .observe(duration);
+        if submission && status.class().is_success() {
+            counts.submissions_accepted += 1;
+        } else if submission {
+            counts.submissions_rejected += 1;
+        }
+        drop(counts);
+    }
+
+    /// The Prometheus Text Format of everything collected so far
+    fn render(&self, store: &Store) -> String {
+        let counts = self.0.lock().expect("No Thread panics while counting");
+        let mut out = String::new();
+
+        out.push_str("# HELP http_requests_total Handled Requests.\n");
+        out.push_str("# TYPE http_requests_total counter\n");
+        for ((method, route, status), count) in &counts.requests {
+            writeln!(
+                out,
+                "http_requests_total{{method=\"{method}\",route=\"{route}\",status=\"{status}\"}} {count}"
+            )
+            .ok();
+        }
+
+        out.push_str("# HELP http_request_duration_seconds Time until the Response was ready.\n");
+        out.push_str("# TYPE http_request_duration_seconds histogram\n");
+        for ((method, route), latency) in &counts.latencies {
+            let labels = format!("method=\"{method}\",route=\"{route}\"");
+            let mut cumulative = 0;
+            for (le, count) in BUCKETS.iter().zip(latency.buckets) {
+                cumulative += count;
+                writeln!(
+                    out,
+                    "http_request_duration_seconds_bucket{{{labels},le=\"{le}\"}} {cumulative}"
+                )
+                .ok();
+            }
+            let (sum, count) = (latency.sum, latency.count);
+            writeln!(
+                out,
+                "http_request_duration_seconds_bucket{{{labels},le=\"+Inf\"}} {count}"
+            )
+            .ok();
+            writeln!(out, "http_request_duration_seconds_sum{{{labels}}} {sum}").ok();
+            writeln!(
+                out,
+                "http_request_duration_seconds_count{{{labels}}} {count}"
+            )
+            .ok();
+        }
+
+        out.push_str("# HELP submissions_total Submitted Runs by Outcome.\n");
+        out.push_str("# TYPE submissions_total counter\n");
+        let (accepted, rejected) = (counts.submissions_accepted, counts.submissions_rejected);
+        drop(counts);
+        writeln!(out, "submissions_total{{result=\"accepted\"}} {accepted}").ok();
+        writeln!(out, "submissions_total{{result=\"rejected\"}} {rejected}").ok();
+
+        if let Some(pool) = store.pool_stats() {
+            out.push_str("# HELP db_pool_connections Connections of the Database Pool.\n");
+            out.push_str("# TYPE db_pool_connections gauge\n");
+            let active = pool.size.saturating_sub(pool.idle);
+            writeln!(out, "db_pool_connections{{state=\"active\"}} {active}").ok();
+            writeln!(out, "db_pool_connections{{state=\"idle\"}} {}", pool.idle).ok();
+            out.push_str("# HELP db_pool_max_connections Most Connections the Pool opens.\n");
+            out.push_str("# TYPE db_pool_max_connections gauge\n");
+            writeln!(out, "db_pool_max_connections {}", pool.max).ok();
+        }
+        out
+    }
+}
+
+/// When the Request arrived
+struct Started(Instant);
+
+/// Feeds every Response into the managed [`Metrics`]
+pub struct Recorder;
+
+#[rocket::async_trait]
+impl Fairing for Recorder {
+    fn info(&self) -> Info {
+        Info {
+            name: "Metrics",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _: &mut Data<'_>) {
+        req.local_cache(|| Started(Instant::now()));
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, res: &mut Response<'r>) {
+        let Some(metrics) = req.rocket().state::<Metrics>() else {
+            return;
+        };
+        let Started(started) = req.local_cache(|| Started(Instant::now()));
+        metrics.record(req, res.status(), started.elapsed());
+    }
+}
+
+/// Everything collected so far, for Prometheus to scrape
+#[rocket::get("/metrics")]
+pub fn metrics(metrics: &State<Metrics>, store: &State<Store>) -> String {
+    metrics.render(store)
+}
@@ -0,0 +1,159 @@
+use crate::{
+    auth::{self, Player},
+    boards,
+    error::Error,
+    limit::{Allowed, Limits},
+    store::Store,
+    DEFAULT_LIMIT, MAX_LIMIT,
+};
+use rocket::{serde::json::Json, State};
+use utils::{validation, Credentials, PlayerName, PlayerRank, RunRecord};
+
+/// How many Entries above and below the Player are included
+const NEIGHBOURS: i64 = 2;
+
+/// Where the best Entry of the Player on the Board places
+#[utoipa::path(
+    get,
+    path = "/players/{name}",
+    params(
+        ("name" = String, Path, description = "Name of the Player"),
+        ("mode" = Option<Mode>, Query, description = "Defaults to `classic`"),
+        ("difficulty" = Option<Difficulty>, Query, description = "Defaults to `normal`"),
+    ),
+    responses(
+        (status = 200, body = PlayerRank),
+        (status = 404, description = "The Player has no Entry on the Board", body = ErrorBody),
+        (status = 422, description = "Unknown Mode or Difficulty", body = ErrorBody),
+    )
+)]
+#[rocket::get("/players/<name>?<mode>&<difficulty>")]
+pub async fn get_player(
+    store: &State<Store>,
+    _allowed: Allowed,
+    name: &str,
+    mode: Option<&str>,
+    difficulty: Option<&str>,
+) -> Result<Json<PlayerRank>, Error> {
+    let (mode, difficulty) = boards::select(mode, difficulty)?;
+    let (total, surrounding) = store
+        .surrounding(name, mode, difficulty, NEIGHBOURS)
+        .await?;
+
+    let Some(idx) = surrounding.iter().position(|entry| entry.name == name) else {
+        return Err(Error::NotFound(format!("{name} has no Entry")));
+    };
+
+    let mut neighbours = surrounding;
+    let entry = neighbours.remove(idx);
+    #[allow(clippy::cast_precision_loss)]
+    let percentile = (total - entry.rank) as f64 / total as f64 * 100.0;
+
+    Ok(Json(PlayerRank {
+        entry,
+        total,
+        percentile,
+        neighbours,
+    }))
+}
+
+/// The Runs of a Player, newest first
+#[utoipa::path(
+    get,
+    path = "/players/{name}/runs",
+    params(
+        ("name" = String, Path, description = "Name of the Player"),
+        ("limit" = Option<i64>, Query, description = "At most 100, defaults to 10"),
+        ("offset" = Option<i64>, Query, description = "Entries to skip"),
+    ),
+    responses((status = 200, body = [RunRecord]))
+)]
+#[rocket::get("/players/<name>/runs?<limit>&<offset>")]
+pub async fn get_runs(
+    store: &State<Store>,
+    _allowed: Allowed,
+    name: &str,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> Result<Json<Vec<RunRecord>>, Error> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(0, MAX_LIMIT);
+    let offset = offset.unwrap_or(0).max(0);
+
+    let runs = store.runs(name, limit, offset).await?;
+    Ok(Json(runs))
+}
+
+/// Claims a Name. The returned Token is needed to submit Runs and can't be recovered.
+#[utoipa::path(
+    post,
+    path = "/players",
+    request_body = PlayerName,
+    responses(
+        (status = 200, body = Credentials),
+        (status = 409, description = "The Name is taken", body = ErrorBody),
+        (status = 422, description = "The Name is invalid", body = ErrorBody),
+    )
+)]
+#[rocket::post("/players", data = "<input>")]
+pub async fn register(
+    store: &State<Store>,
+    _allowed: Allowed,
+    input: Json<PlayerName>,
+) -> Result<Json<Credentials>, Error> {
+    validation::name(&input.name)?;
+
+    let token = auth::generate_token();
+    store.register(&input.name, &auth::hash(&token)).await?;
+
+    Ok(Json(Credentials {
+        name: input.name.clone(),
+        token,
+    }))
+}
+
+/// Moves the Player and all their Runs to a new Name
+#[utoipa::path(
+    post,
+    path = "/players/rename",
+    request_body = PlayerName,
+    security(("token" = [])),
+    responses(
+        (status = 200),
+        (status = 401, body = ErrorBody),
+        (status = 403, description = "The Player is banned", body = ErrorBody),
+        (status = 409, description = "The Name is taken", body = ErrorBody),
+        (status = 422, description = "The Name is invalid", body = ErrorBody),
+    )
+)]
+#[rocket::post("/players/rename", data = "<input>")]
+pub async fn rename(
+    store: &State<Store>,
+    limits: &State<Limits>,
+    _allowed: Allowed,
+    player: Player,
+    input: Json<PlayerName>,
+) -> Result<(), Error> {
+    limits.unbanned(&player.name)?;
+    if store.banned(&player.name).await? {
+        return Err(Error::Banned);
+    }
+    validation::name(&input.name)?;
+    if input.name == player.name {
+        return Ok(());
+    }
+
+    store.rename(&player.name, &input.name).await?;
+    Ok(())
+}
+
+/// Deletes the Account of the Player with all their Runs
+#[utoipa::path(
+    delete,
+    path = "/players",
+    security(("token" = [])),
+    responses((status = 200), (status = 401, body = ErrorBody))
+)]
+#[rocket::delete("/players")]
+pub async fn delete(store: &State<Store>, _allowed: Allowed, player: Player) -> Result<(), Error> {
+    Ok(store.delete(&player).await?)
+}
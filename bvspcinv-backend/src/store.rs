@@ -0,0 +1,134 @@
+use crate::auth::Player;
+use std::fmt;
+use utils::{
+    AuditRecord, Difficulty, Entry, ExportedRun, Imported, Mode, ModeratedEntry, OnConflict, Page,
+    RankedEntry, Recorded, Run, RunRecord,
+};
+
+#[cfg(any(test, not(feature = "shuttle")))]
+mod memory;
+mod postgres;
+
+#[cfg(any(test, not(feature = "shuttle")))]
+pub use memory::Memory;
+pub use postgres::Postgres;
+
+/// The Store the Handlers work with
+pub type Store = Box<dyn ScoreStore>;
+
+pub type Result<T, E = StoreError> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum StoreError {
+    /// Another Player owns the Name
+    NameTaken(String),
+    /// The Name already has an Entry
+    EntryExists(String),
+    /// The Store itself failed
+    Backend(String),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NameTaken(name) => write!(f, "The Name {name} is already taken"),
+            Self::EntryExists(name) => write!(f, "{name} already has an Entry"),
+            Self::Backend(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// Connections of a Database Pool
+pub struct PoolStats {
+    pub size: u32,
+    pub idle: u32,
+    pub max: u32,
+}
+
+/// Where Players, their Runs and the Leaderboards are kept.
+/// Every Mode and Difficulty has its own Board, a Player has an Entry on each they played.
+/// Hidden Entries are left out of everything but [`ScoreStore::moderated`].
+#[rocket::async_trait]
+pub trait ScoreStore: Send + Sync {
+    /// Checks that the Store can be reached
+    async fn ping(&self) -> Result<()>;
+
+    /// Connections of the Pool, if the Store has one
+    fn pool_stats(&self) -> Option<PoolStats> {
+        None
+    }
+
+    /// The best Entry of every Player on the Board, best first
+    async fn entries(&self, mode: Mode, difficulty: Difficulty) -> Result<Vec<Entry>>;
+
+    /// The best Run of each Player on the Board submitted since the Unix Timestamp, ranked
+    async fn top(
+        &self,
+        mode: Mode,
+        difficulty: Difficulty,
+        since: Option<i64>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Page>;
+
+    /// Number of ranked Players on the Board and the Entries at most `around`
+    /// Positions away from the Player's, best first. Empty if the Player is unranked.
+    async fn surrounding(
+        &self,
+        name: &str,
+        mode: Mode,
+        difficulty: Difficulty,
+        around: i64,
+    ) -> Result<(i64, Vec<RankedEntry>)>;
+
+    /// The Runs of a Player, newest first
+    async fn runs(&self, name: &str, limit: i64, offset: i64) -> Result<Vec<RunRecord>>;
+
+    /// Stores the Run and raises the Player's best Score on its Board if it beats it.
+    /// Concurrent Runs of one Player must never lower the best Score.
+    async fn insert_run(&self, name: &str, run: &Run) -> Result<Recorded>;
+
+    /// Claims the Name for the Owner of the Token
+    async fn register(&self, name: &str, token_hash: &str) -> Result<()>;
+
+    /// The Player owning the Token
+    async fn player(&self, token_hash: &str) -> Result<Option<Player>>;
+
+    /// Moves the Entry, Account, Runs and Ban of a Name to a new one.
+    /// Returns whether anything was stored under the old Name.
+    async fn rename(&self, old: &str, new: &str) -> Result<bool>;
+
+    /// Removes the Player together with all their Runs
+    async fn delete(&self, player: &Player) -> Result<()>;
+
+    /// Every Entry on every Board including hidden ones, best first
+    async fn moderated(&self, limit: i64, offset: i64) -> Result<Vec<ModeratedEntry>>;
+
+    /// Hides or shows the Entries of the Name. Returns whether there are any.
+    async fn hide(&self, name: &str, hidden: bool) -> Result<bool>;
+
+    /// Deletes the Entries and all Runs of the Name, but not the Account.
+    /// Returns whether there was anything to delete.
+    async fn remove(&self, name: &str) -> Result<bool>;
+
+    /// Bans the Name and hides its Entries
+    async fn ban(&self, name: &str, reason: &str) -> Result<()>;
+
+    /// Lifts the Ban. Returns whether the Name was banned.
+    async fn unban(&self, name: &str) -> Result<bool>;
+
+    async fn banned(&self, name: &str) -> Result<bool>;
+
+    /// Records a Moderation Action in the Audit Log
+    async fn audit(&self, action: &str, target: &str, detail: Option<&str>) -> Result<()>;
+
+    /// The Audit Log, newest first
+    async fn audit_log(&self, limit: i64, offset: i64) -> Result<Vec<AuditRecord>>;
+
+    /// Every Run with its Owner, oldest first
+    async fn export(&self) -> Result<Vec<ExportedRun>>;
+
+    /// Adds the Runs and rebuilds the Entries of their Names from them.
+    /// Either everything is imported or nothing.
+    async fn import(&self, runs: &[ExportedRun], on_conflict: OnConflict) -> Result<Imported>;
+}
@@ -0,0 +1,422 @@
+use super::{Result, ScoreStore, StoreError};
+use crate::auth::Player;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Mutex, MutexGuard},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use utils::{
+    AuditRecord, Difficulty, Entry, ExportedRun, Imported, Mode, ModeratedEntry, OnConflict, Page,
+    RankedEntry, Recorded, Run, RunRecord,
+};
+
+/// Keeps everything in Memory. Meant for Tests and local Experiments.
+#[derive(Default)]
+pub struct Memory(Mutex<Data>);
+
+#[derive(Default)]
+struct Data {
+    players: Vec<Account>,
+    runs: Vec<(String, RunRecord)>,
+    next_id: i64,
+    hidden: HashSet<String>,
+    /// Reason of every Ban
+    bans: HashMap<String, String>,
+    audit_log: Vec<AuditRecord>,
+}
+
+struct Account {
+    id: i64,
+    name: String,
+    token_hash: String,
+}
+
+impl Memory {
+    fn lock(&self) -> Result<MutexGuard<'_, Data>> {
+        self.0
+            .lock()
+            .map_err(|_| StoreError::Backend("The Store is poisoned".into()))
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_secs().try_into().unwrap_or(i64::MAX))
+}
+
+impl Data {
+    /// The best Run of each Name on each Board submitted since the Unix Timestamp, best first
+    fn best(&self, since: Option<i64>) -> Vec<(&str, &RunRecord)> {
+        let mut best: Vec<(&str, &RunRecord)> = Vec::new();
+        for (name, run) in &self.runs {
+            if since.is_some_and(|since| run.submitted_at < since) {
+                continue;
+            }
+            let same_board = |(owner, best): &&mut (&str, &RunRecord)| {
+                owner == name && best.mode == run.mode && best.difficulty == run.difficulty
+            };
+            match best.iter_mut().find(same_board) {
+                Some((_, best)) if best.score < run.score => *best = run,
+                Some(_) => {}
+                None => best.push((name, run)),
+            }
+        }
+        best.sort_by(|(a, a_run), (b, b_run)| b_run.score.cmp(&a_run.score).then_with(|| a.cmp(b)));
+        best
+    }
+
+    /// The best visible Run of each Player on the Board submitted since the Unix Timestamp, ranked
+    fn ranked(&self, mode: Mode, difficulty: Difficulty, since: Option<i64>) -> Vec<RankedEntry> {
+        let visible = self.best(since).into_iter().filter(|(name, run)| {
+            run.mode == mode && run.difficulty == difficulty && !self.hidden.contains(*name)
+        });
+
+        // Equal Scores share the Rank of the first of them
+        let mut ranked: Vec<RankedEntry> = Vec::new();
+        for (position, (name, run)) in (1..).zip(visible) {
+            let rank = match ranked.last() {
+                Some(last) if last.score == run.score => last.rank,
+                _ => position,
+            };
+            ranked.push(RankedEntry {
+                rank,
+                name: name.to_owned(),
+                score: run.score,
+                level: run.level,
+                client_version: run.client_version.clone(),
+                mode,
+                difficulty,
+            });
+        }
+        ranked
+    }
+
+    fn name_in_use(&self, name: &str) -> bool {
+        self.players.iter().any(|account| account.name == name)
+            || self.runs.iter().any(|(owner, _)| owner == name)
+    }
+}
+
+fn page<T>(items: Vec<T>, limit: i64, offset: i64) -> Vec<T> {
+    let skip = usize::try_from(offset).unwrap_or(0);
+    let take = usize::try_from(limit).unwrap_or(0);
+    items.into_iter().skip(skip).take(take).collect()
+}
+
+#[rocket::async_trait]
+impl ScoreStore for Memory {
+    async fn ping(&self) -> Result<()> {
+        self.lock().map(drop)
+    }
+
+    async fn entries(&self, mode: Mode, difficulty: Difficulty) -> Result<Vec<Entry>> {
+        Ok(self
+            .lock()?
+            .ranked(mode, difficulty, None)
+            .into_iter()
+            .map(|entry| Entry {
+                name: entry.name,
+                score: entry.score,
+                level: entry.level,
+                client_version: entry.client_version,
+                mode: entry.mode,
+                difficulty: entry.difficulty,
+            })
+            .collect())
+    }
+
+    async fn top(
+        &self,
+        mode: Mode,
+        difficulty: Difficulty,
+        since: Option<i64>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Page> {
+        let ranked = self.lock()?.ranked(mode, difficulty, since);
+        Ok(Page {
+            total: ranked.len().try_into().unwrap_or(i64::MAX),
+            entries: page(ranked, limit, offset),
+        })
+    }
+
+    async fn surrounding(
+        &self,
+        name: &str,
+        mode: Mode,
+        difficulty: Difficulty,
+        around: i64,
+    ) -> Result<(i64, Vec<RankedEntry>)> {
+        let ranked = self.lock()?.ranked(mode, difficulty, None);
+        let total = ranked.len().try_into().unwrap_or(i64::MAX);
+        let Some(position) = ranked.iter().position(|entry| entry.name == name) else {
+            return Ok((total, Vec::new()));
+        };
+
+        let around = usize::try_from(around).unwrap_or(0);
+        let surrounding = ranked
+            .into_iter()
+            .skip(position.saturating_sub(around))
+            .take(2 * around + 1)
+            .collect();
+        Ok((total, surrounding))
+    }
+
+    async fn runs(&self, name: &str, limit: i64, offset: i64) -> Result<Vec<RunRecord>> {
+        let runs = self
+            .lock()?
+            .runs
+            .iter()
+            .rev()
+            .filter(|(owner, _)| owner == name)
+            .map(|(_, run)| run.clone())
+            .collect();
+        Ok(page(runs, limit, offset))
+    }
+
+    async fn insert_run(&self, name: &str, run: &Run) -> Result<Recorded> {
+        let record = RunRecord {
+            score: run.score,
+            level: run.level,
+            duration_secs: run.duration_secs,
+            client_version: run.client_version.clone(),
+            submitted_at: now(),
+            mode: run.mode,
+            difficulty: run.difficulty,
+        };
+
+        let mut data = self.lock()?;
+        let previous = data
+            .runs
+            .iter()
+            .filter(|(owner, previous)| {
+                owner == name && previous.mode == run.mode && previous.difficulty == run.difficulty
+            })
+            .map(|(_, run)| run.score)
+            .max();
+        data.runs.push((name.to_owned(), record));
+        drop(data);
+
+        Ok(Recorded {
+            personal_best: previous.is_none_or(|best| best < run.score),
+            best: previous.map_or(run.score, |best| best.max(run.score)),
+        })
+    }
+
+    async fn register(&self, name: &str, token_hash: &str) -> Result<()> {
+        let mut data = self.lock()?;
+        if data.players.iter().any(|account| account.name == name) {
+            return Err(StoreError::NameTaken(name.to_owned()));
+        }
+
+        data.next_id += 1;
+        let id = data.next_id;
+        data.players.push(Account {
+            id,
+            name: name.to_owned(),
+            token_hash: token_hash.to_owned(),
+        });
+        drop(data);
+        Ok(())
+    }
+
+    async fn player(&self, token_hash: &str) -> Result<Option<Player>> {
+        Ok(self
+            .lock()?
+            .players
+            .iter()
+            .find(|account| account.token_hash == token_hash)
+            .map(|account| Player {
+                id: account.id,
+                name: account.name.clone(),
+            }))
+    }
+
+    async fn rename(&self, old: &str, new: &str) -> Result<bool> {
+        let mut data = self.lock()?;
+        if data.name_in_use(new) {
+            return Err(StoreError::NameTaken(new.to_owned()));
+        }
+
+        let mut renamed = false;
+        for account in data.players.iter_mut().filter(|a| a.name == old) {
+            new.clone_into(&mut account.name);
+            renamed = true;
+        }
+        for (owner, _) in data.runs.iter_mut().filter(|(o, _)| o == old) {
+            new.clone_into(owner);
+            renamed = true;
+        }
+        if data.hidden.remove(old) {
+            data.hidden.insert(new.to_owned());
+        }
+        if let Some(reason) = data.bans.remove(old) {
+            data.bans.insert(new.to_owned(), reason);
+        }
+        drop(data);
+        Ok(renamed)
+    }
+
+    async fn delete(&self, player: &Player) -> Result<()> {
+        let mut data = self.lock()?;
+        data.players.retain(|account| account.id != player.id);
+        data.runs.retain(|(owner, _)| *owner != player.name);
+        drop(data);
+        Ok(())
+    }
+
+    async fn moderated(&self, limit: i64, offset: i64) -> Result<Vec<ModeratedEntry>> {
+        let data = self.lock()?;
+        let entries = data
+            .best(None)
+            .into_iter()
+            .map(|(name, run)| ModeratedEntry {
+                name: name.to_owned(),
+                score: run.score,
+                hidden: data.hidden.contains(name),
+                mode: run.mode,
+                difficulty: run.difficulty,
+            })
+            .collect();
+        drop(data);
+        Ok(page(entries, limit, offset))
+    }
+
+    async fn hide(&self, name: &str, hidden: bool) -> Result<bool> {
+        let mut data = self.lock()?;
+        if !data.runs.iter().any(|(owner, _)| owner == name) {
+            return Ok(false);
+        }
+        if hidden {
+            data.hidden.insert(name.to_owned());
+        } else {
+            data.hidden.remove(name);
+        }
+        drop(data);
+        Ok(true)
+    }
+
+    async fn remove(&self, name: &str) -> Result<bool> {
+        let mut data = self.lock()?;
+        let len = data.runs.len();
+        data.runs.retain(|(owner, _)| owner != name);
+        data.hidden.remove(name);
+        let removed = data.runs.len() != len;
+        drop(data);
+        Ok(removed)
+    }
+
+    async fn ban(&self, name: &str, reason: &str) -> Result<()> {
+        let mut data = self.lock()?;
+        data.bans.insert(name.to_owned(), reason.to_owned());
+        data.hidden.insert(name.to_owned());
+        drop(data);
+        Ok(())
+    }
+
+    async fn unban(&self, name: &str) -> Result<bool> {
+        Ok(self.lock()?.bans.remove(name).is_some())
+    }
+
+    async fn banned(&self, name: &str) -> Result<bool> {
+        Ok(self.lock()?.bans.contains_key(name))
+    }
+
+    async fn audit(&self, action: &str, target: &str, detail: Option<&str>) -> Result<()> {
+        self.lock()?.audit_log.push(AuditRecord {
+            action: action.to_owned(),
+            target: target.to_owned(),
+            detail: detail.map(str::to_owned),
+            created_at: now(),
+        });
+        Ok(())
+    }
+
+    async fn audit_log(&self, limit: i64, offset: i64) -> Result<Vec<AuditRecord>> {
+        let records = self.lock()?.audit_log.iter().rev().cloned().collect();
+        Ok(page(records, limit, offset))
+    }
+
+    async fn export(&self) -> Result<Vec<ExportedRun>> {
+        let data = self.lock()?;
+        let runs = data
+            .runs
+            .iter()
+            .map(|(name, run)| ExportedRun {
+                name: name.clone(),
+                score: run.score,
+                level: run.level,
+                duration_secs: run.duration_secs,
+                client_version: run.client_version.clone(),
+                hidden: data.hidden.contains(name),
+                submitted_at: run.submitted_at,
+                mode: run.mode,
+                difficulty: run.difficulty,
+            })
+            .collect();
+        drop(data);
+        Ok(runs)
+    }
+
+    async fn import(&self, runs: &[ExportedRun], on_conflict: OnConflict) -> Result<Imported> {
+        let mut names: Vec<&str> = runs.iter().map(|run| run.name.as_str()).collect();
+        names.sort_unstable();
+        names.dedup();
+
+        let mut data = self.lock()?;
+        let existing: HashSet<&str> = names
+            .iter()
+            .copied()
+            .filter(|name| data.runs.iter().any(|(owner, _)| owner == name))
+            .collect();
+        match on_conflict {
+            OnConflict::Fail => {
+                if let Some(name) = names.iter().find(|name| existing.contains(*name)) {
+                    return Err(StoreError::EntryExists((*name).to_owned()));
+                }
+            }
+            OnConflict::Overwrite => {
+                data.runs
+                    .retain(|(owner, _)| !existing.contains(owner.as_str()));
+                data.hidden.retain(|name| !existing.contains(name.as_str()));
+            }
+            OnConflict::Skip => {}
+        }
+
+        let skip = |run: &&ExportedRun| {
+            on_conflict == OnConflict::Skip && existing.contains(run.name.as_str())
+        };
+        let mut imported = 0;
+        for run in runs.iter().filter(|run| !skip(run)) {
+            if run.hidden {
+                data.hidden.insert(run.name.clone());
+            }
+            data.runs.push((
+                run.name.clone(),
+                RunRecord {
+                    score: run.score,
+                    level: run.level,
+                    duration_secs: run.duration_secs,
+                    client_version: run.client_version.clone(),
+                    submitted_at: run.submitted_at,
+                    mode: run.mode,
+                    difficulty: run.difficulty,
+                },
+            ));
+            imported += 1;
+        }
+        drop(data);
+
+        let skipped = if on_conflict == OnConflict::Skip {
+            existing.len()
+        } else {
+            0
+        };
+        Ok(Imported {
+            entries: (names.len() - skipped).try_into().unwrap_or(i64::MAX),
+            runs: imported,
+            skipped: skipped.try_into().unwrap_or(i64::MAX),
+        })
+    }
+}
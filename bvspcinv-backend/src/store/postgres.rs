@@ -0,0 +1,586 @@
+use super::{PoolStats, Result, ScoreStore, StoreError};
+use crate::auth::Player;
+use sqlx::{error::DatabaseError, migrate::MigrateError, PgPool};
+use utils::{
+    AuditRecord, Difficulty, Entry, ExportedRun, Imported, Mode, ModeratedEntry, OnConflict, Page,
+    RankedEntry, Recorded, Run, RunRecord,
+};
+
+pub struct Postgres {
+    pool: PgPool,
+}
+
+impl Postgres {
+    /// Applies every Migration in `migrations/` that hasn't run yet.
+    /// Applied Versions are tracked in the `_sqlx_migrations` Table.
+    pub async fn migrate(pool: PgPool) -> Result<Self, MigrateError> {
+        sqlx::migrate!().run(&pool).await?;
+        Ok(Self { pool })
+    }
+}
+
+fn backend(context: &str) -> impl FnOnce(sqlx::Error) -> StoreError + '_ {
+    move |e| StoreError::Backend(format!("{context}: {e}"))
+}
+
+/// One Field of every Run, to bind it as an Array for `UNNEST`
+fn column<'a, T>(runs: &[&'a ExportedRun], field: impl Fn(&'a ExportedRun) -> T) -> Vec<T> {
+    runs.iter().map(|&run| field(run)).collect()
+}
+
+fn taken(name: &str) -> impl FnOnce(sqlx::Error) -> StoreError + '_ {
+    move |e| {
+        if e.as_database_error()
+            .is_some_and(DatabaseError::is_unique_violation)
+        {
+            StoreError::NameTaken(name.to_owned())
+        } else {
+            StoreError::Backend(format!("Couldn't claim Name: {e}"))
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl ScoreStore for Postgres {
+    async fn ping(&self) -> Result<()> {
+        sqlx::query("SELECT 1;")
+            .execute(&self.pool)
+            .await
+            .map_err(backend("Couldn't reach the Database"))?;
+        Ok(())
+    }
+
+    fn pool_stats(&self) -> Option<PoolStats> {
+        Some(PoolStats {
+            size: self.pool.size(),
+            idle: self.pool.num_idle().try_into().unwrap_or(u32::MAX),
+            max: self.pool.options().get_max_connections(),
+        })
+    }
+
+    async fn entries(&self, mode: Mode, difficulty: Difficulty) -> Result<Vec<Entry>> {
+        sqlx::query_as::<_, Entry>(
+            "SELECT name, score, level, client_version, mode, difficulty FROM entries
+             WHERE mode = $1 AND difficulty = $2
+               AND name NOT IN (SELECT name FROM hidden_names)
+             ORDER BY score DESC;",
+        )
+        .bind(mode)
+        .bind(difficulty)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend("Couldn't get Entries"))
+    }
+
+    async fn top(
+        &self,
+        mode: Mode,
+        difficulty: Difficulty,
+        since: Option<i64>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Page> {
+        let total = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(DISTINCT name) FROM runs
+             WHERE mode = $2 AND difficulty = $3
+               AND ($1::BIGINT IS NULL OR submitted_at >= to_timestamp($1))
+               AND name NOT IN (SELECT name FROM hidden_names);",
+        )
+        .bind(since)
+        .bind(mode)
+        .bind(difficulty)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(backend("Couldn't count Players"))?;
+
+        let entries = sqlx::query_as::<_, RankedEntry>(
+            "WITH best AS (
+               SELECT DISTINCT ON (name) name, score, level, client_version, mode, difficulty
+               FROM runs
+               WHERE mode = $4 AND difficulty = $5
+                 AND ($1::BIGINT IS NULL OR submitted_at >= to_timestamp($1))
+                 AND name NOT IN (SELECT name FROM hidden_names)
+               ORDER BY name, score DESC, submitted_at
+             )
+             SELECT RANK() OVER (ORDER BY score DESC) AS rank,
+                    name, score, level, client_version, mode, difficulty
+             FROM best
+             ORDER BY score DESC, name LIMIT $2 OFFSET $3;",
+        )
+        .bind(since)
+        .bind(limit)
+        .bind(offset)
+        .bind(mode)
+        .bind(difficulty)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend("Couldn't rank Runs"))?;
+
+        Ok(Page { total, entries })
+    }
+
+    async fn surrounding(
+        &self,
+        name: &str,
+        mode: Mode,
+        difficulty: Difficulty,
+        around: i64,
+    ) -> Result<(i64, Vec<RankedEntry>)> {
+        let total = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM entries
+             WHERE mode = $1 AND difficulty = $2
+               AND name NOT IN (SELECT name FROM hidden_names);",
+        )
+        .bind(mode)
+        .bind(difficulty)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(backend("Couldn't count Entries"))?;
+
+        let surrounding = sqlx::query_as::<_, RankedEntry>(
+            "WITH ranked AS (
+               SELECT RANK() OVER (ORDER BY score DESC) AS rank,
+                      ROW_NUMBER() OVER (ORDER BY score DESC, name) AS position,
+                      name, score, level, client_version, mode, difficulty
+               FROM entries
+               WHERE mode = $3 AND difficulty = $4
+                 AND name NOT IN (SELECT name FROM hidden_names)
+             ), player AS (
+               SELECT position FROM ranked WHERE name = $1
+             )
+             SELECT rank, name, score, level, client_version, mode, difficulty
+             FROM ranked, player
+             WHERE ranked.position BETWEEN player.position - $2 AND player.position + $2
+             ORDER BY ranked.position;",
+        )
+        .bind(name)
+        .bind(around)
+        .bind(mode)
+        .bind(difficulty)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend("Couldn't rank Player"))?;
+
+        Ok((total, surrounding))
+    }
+
+    async fn runs(&self, name: &str, limit: i64, offset: i64) -> Result<Vec<RunRecord>> {
+        sqlx::query_as::<_, RunRecord>(
+            "SELECT score, level, duration_secs, client_version,
+                    EXTRACT(EPOCH FROM submitted_at)::BIGINT AS submitted_at, mode, difficulty
+             FROM runs WHERE name = $1
+             ORDER BY runs.submitted_at DESC, id DESC LIMIT $2 OFFSET $3;",
+        )
+        .bind(name)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend("Couldn't get Runs"))
+    }
+
+    async fn insert_run(&self, name: &str, run: &Run) -> Result<Recorded> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(backend("Couldn't start Transaction"))?;
+
+        sqlx::query(
+            "INSERT INTO runs (name, score, level, duration_secs, client_version, mode, difficulty)
+             VALUES ($1, $2, $3, $4, $5, $6, $7);",
+        )
+        .bind(name)
+        .bind(run.score)
+        .bind(run.level)
+        .bind(run.duration_secs)
+        .bind(&run.client_version)
+        .bind(run.mode)
+        .bind(run.difficulty)
+        .execute(&mut *tx)
+        .await
+        .map_err(backend("Couldn't Insert Run"))?;
+
+        // The Row is locked on Conflict, so the Comparison sees the latest Score
+        let raised = sqlx::query_scalar::<_, i64>(
+            "INSERT INTO entries (name, score, level, client_version, mode, difficulty)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT (name, mode, difficulty) DO UPDATE
+             SET score = EXCLUDED.score,
+                 level = EXCLUDED.level, client_version = EXCLUDED.client_version,
+                 updated_at = now()
+             WHERE entries.score < EXCLUDED.score
+             RETURNING score;",
+        )
+        .bind(name)
+        .bind(run.score)
+        .bind(run.level)
+        .bind(&run.client_version)
+        .bind(run.mode)
+        .bind(run.difficulty)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(backend("Couldn't Insert or Update Entry"))?;
+
+        let recorded = match raised {
+            Some(best) => Recorded {
+                personal_best: true,
+                best,
+            },
+            None => Recorded {
+                personal_best: false,
+                best: sqlx::query_scalar::<_, i64>(
+                    "SELECT score FROM entries WHERE name = $1 AND mode = $2 AND difficulty = $3;",
+                )
+                .bind(name)
+                .bind(run.mode)
+                .bind(run.difficulty)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(backend("Couldn't get Entry"))?,
+            },
+        };
+
+        tx.commit().await.map_err(backend("Couldn't commit Run"))?;
+        Ok(recorded)
+    }
+
+    async fn register(&self, name: &str, token_hash: &str) -> Result<()> {
+        sqlx::query("INSERT INTO players (name, token_hash) VALUES ($1, $2);")
+            .bind(name)
+            .bind(token_hash)
+            .execute(&self.pool)
+            .await
+            .map_err(taken(name))?;
+        Ok(())
+    }
+
+    async fn player(&self, token_hash: &str) -> Result<Option<Player>> {
+        sqlx::query_as::<_, Player>("SELECT id, name FROM players WHERE token_hash = $1")
+            .bind(token_hash)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(backend("Couldn't authenticate Player"))
+    }
+
+    async fn rename(&self, old: &str, new: &str) -> Result<bool> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(backend("Couldn't start Transaction"))?;
+
+        // Unclaimed Names from before Accounts still have Entries
+        let in_use =
+            sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM entries WHERE name = $1);")
+                .bind(new)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(backend("Couldn't look up Name"))?;
+        if in_use {
+            return Err(StoreError::NameTaken(new.to_owned()));
+        }
+
+        let mut renamed = sqlx::query("UPDATE players SET name = $2 WHERE name = $1;")
+            .bind(old)
+            .bind(new)
+            .execute(&mut *tx)
+            .await
+            .map_err(taken(new))?
+            .rows_affected();
+
+        for table in ["entries", "runs", "bans", "hidden_names"] {
+            renamed += sqlx::query(&format!("UPDATE {table} SET name = $2 WHERE name = $1;"))
+                .bind(old)
+                .bind(new)
+                .execute(&mut *tx)
+                .await
+                .map_err(backend("Couldn't rename"))?
+                .rows_affected();
+        }
+
+        tx.commit()
+            .await
+            .map_err(backend("Couldn't commit Rename"))?;
+        Ok(renamed > 0)
+    }
+
+    async fn delete(&self, player: &Player) -> Result<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(backend("Couldn't start Transaction"))?;
+
+        for table in ["entries", "runs"] {
+            sqlx::query(&format!("DELETE FROM {table} WHERE name = $1;"))
+                .bind(&player.name)
+                .execute(&mut *tx)
+                .await
+                .map_err(backend("Couldn't delete"))?;
+        }
+        sqlx::query("DELETE FROM players WHERE id = $1;")
+            .bind(player.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(backend("Couldn't delete Player"))?;
+
+        tx.commit()
+            .await
+            .map_err(backend("Couldn't commit Deletion"))
+    }
+
+    async fn moderated(&self, limit: i64, offset: i64) -> Result<Vec<ModeratedEntry>> {
+        sqlx::query_as::<_, ModeratedEntry>(
+            "SELECT name, score, name IN (SELECT name FROM hidden_names) AS hidden,
+                    mode, difficulty
+             FROM entries ORDER BY score DESC, name, mode, difficulty LIMIT $1 OFFSET $2;",
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend("Couldn't get Entries"))
+    }
+
+    async fn hide(&self, name: &str, hidden: bool) -> Result<bool> {
+        let exists =
+            sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM entries WHERE name = $1);")
+                .bind(name)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(backend("Couldn't look up Entry"))?;
+        if !exists {
+            return Ok(false);
+        }
+
+        let query = if hidden {
+            "INSERT INTO hidden_names (name) VALUES ($1) ON CONFLICT (name) DO NOTHING;"
+        } else {
+            "DELETE FROM hidden_names WHERE name = $1;"
+        };
+        sqlx::query(query)
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(backend("Couldn't hide Entry"))?;
+        Ok(true)
+    }
+
+    async fn remove(&self, name: &str) -> Result<bool> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(backend("Couldn't start Transaction"))?;
+
+        let mut removed = 0;
+        for table in ["entries", "runs"] {
+            removed += sqlx::query(&format!("DELETE FROM {table} WHERE name = $1;"))
+                .bind(name)
+                .execute(&mut *tx)
+                .await
+                .map_err(backend("Couldn't remove"))?
+                .rows_affected();
+        }
+        sqlx::query("DELETE FROM hidden_names WHERE name = $1;")
+            .bind(name)
+            .execute(&mut *tx)
+            .await
+            .map_err(backend("Couldn't show Entry"))?;
+
+        tx.commit()
+            .await
+            .map_err(backend("Couldn't commit Removal"))?;
+        Ok(removed > 0)
+    }
+
+    async fn ban(&self, name: &str, reason: &str) -> Result<()> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(backend("Couldn't start Transaction"))?;
+
+        sqlx::query(
+            "INSERT INTO bans (name, reason) VALUES ($1, $2)
+             ON CONFLICT (name) DO UPDATE SET reason = EXCLUDED.reason;",
+        )
+        .bind(name)
+        .bind(reason)
+        .execute(&mut *tx)
+        .await
+        .map_err(backend("Couldn't ban"))?;
+
+        sqlx::query("INSERT INTO hidden_names (name) VALUES ($1) ON CONFLICT (name) DO NOTHING;")
+            .bind(name)
+            .execute(&mut *tx)
+            .await
+            .map_err(backend("Couldn't hide Entry"))?;
+
+        tx.commit().await.map_err(backend("Couldn't commit Ban"))
+    }
+
+    async fn unban(&self, name: &str) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM bans WHERE name = $1;")
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(backend("Couldn't unban"))?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn banned(&self, name: &str) -> Result<bool> {
+        sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM bans WHERE name = $1);")
+            .bind(name)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(backend("Couldn't look up Ban"))
+    }
+
+    async fn audit(&self, action: &str, target: &str, detail: Option<&str>) -> Result<()> {
+        sqlx::query("INSERT INTO audit_log (action, target, detail) VALUES ($1, $2, $3);")
+            .bind(action)
+            .bind(target)
+            .bind(detail)
+            .execute(&self.pool)
+            .await
+            .map_err(backend("Couldn't write Audit Log"))?;
+        Ok(())
+    }
+
+    async fn audit_log(&self, limit: i64, offset: i64) -> Result<Vec<AuditRecord>> {
+        sqlx::query_as::<_, AuditRecord>(
+            "SELECT action, target, detail,
+                    EXTRACT(EPOCH FROM created_at)::BIGINT AS created_at
+             FROM audit_log ORDER BY id DESC LIMIT $1 OFFSET $2;",
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend("Couldn't get Audit Log"))
+    }
+
+    async fn export(&self) -> Result<Vec<ExportedRun>> {
+        sqlx::query_as::<_, ExportedRun>(
+            "SELECT name, score, level, duration_secs, client_version,
+                    name IN (SELECT name FROM hidden_names) AS hidden,
+                    EXTRACT(EPOCH FROM submitted_at)::BIGINT AS submitted_at, mode, difficulty
+             FROM runs ORDER BY id;",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(backend("Couldn't export Runs"))
+    }
+
+    async fn import(&self, runs: &[ExportedRun], on_conflict: OnConflict) -> Result<Imported> {
+        let mut names: Vec<&str> = runs.iter().map(|run| run.name.as_str()).collect();
+        names.sort_unstable();
+        names.dedup();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(backend("Couldn't start Transaction"))?;
+        let existing = sqlx::query_scalar::<_, String>(
+            "SELECT name FROM entries WHERE name = ANY($1) ORDER BY name;",
+        )
+        .bind(&names)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(backend("Couldn't look up Entries"))?;
+
+        match on_conflict {
+            OnConflict::Fail => {
+                if let Some(name) = existing.first() {
+                    return Err(StoreError::EntryExists(name.clone()));
+                }
+            }
+            OnConflict::Overwrite => {
+                for table in ["runs", "entries", "hidden_names"] {
+                    sqlx::query(&format!("DELETE FROM {table} WHERE name = ANY($1);"))
+                        .bind(&existing)
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(backend("Couldn't overwrite Entries"))?;
+                }
+            }
+            OnConflict::Skip => {}
+        }
+
+        let skipped = if on_conflict == OnConflict::Skip {
+            existing
+        } else {
+            Vec::new()
+        };
+        let runs: Vec<_> = runs
+            .iter()
+            .filter(|run| !skipped.contains(&run.name))
+            .collect();
+        let imported: Vec<&str> = names
+            .into_iter()
+            .filter(|name| !skipped.iter().any(|skipped| skipped == name))
+            .collect();
+        let hidden: Vec<&str> = runs
+            .iter()
+            .filter(|run| run.hidden)
+            .map(|run| run.name.as_str())
+            .collect();
+
+        sqlx::query(
+            "INSERT INTO runs
+               (name, score, level, duration_secs, client_version, submitted_at, mode, difficulty)
+             SELECT name, score, level, duration_secs, client_version,
+                    to_timestamp(submitted_at), mode, difficulty
+             FROM UNNEST($1::TEXT[], $2::BIGINT[], $3::INTEGER[], $4::INTEGER[], $5::TEXT[],
+                         $6::BIGINT[], $7::TEXT[], $8::TEXT[])
+             AS backup (name, score, level, duration_secs, client_version, submitted_at,
+                        mode, difficulty);",
+        )
+        .bind(column(&runs, |run| run.name.as_str()))
+        .bind(column(&runs, |run| run.score))
+        .bind(column(&runs, |run| run.level))
+        .bind(column(&runs, |run| run.duration_secs))
+        .bind(column(&runs, |run| run.client_version.as_str()))
+        .bind(column(&runs, |run| run.submitted_at))
+        .bind(column(&runs, |run| run.mode.as_str()))
+        .bind(column(&runs, |run| run.difficulty.as_str()))
+        .execute(&mut *tx)
+        .await
+        .map_err(backend("Couldn't import Runs"))?;
+
+        // The best Run of each Name on each Board becomes its Entry, like on Submission
+        sqlx::query(
+            "INSERT INTO entries
+               (name, mode, difficulty, score, level, client_version, updated_at)
+             SELECT DISTINCT ON (name, mode, difficulty)
+                    name, mode, difficulty, score, level, client_version, submitted_at
+             FROM runs WHERE name = ANY($1)
+             ORDER BY name, mode, difficulty, score DESC, submitted_at;",
+        )
+        .bind(&imported)
+        .execute(&mut *tx)
+        .await
+        .map_err(backend("Couldn't rebuild Entries"))?;
+
+        sqlx::query(
+            "INSERT INTO hidden_names (name) SELECT UNNEST($1::TEXT[])
+             ON CONFLICT (name) DO NOTHING;",
+        )
+        .bind(&hidden)
+        .execute(&mut *tx)
+        .await
+        .map_err(backend("Couldn't hide Entries"))?;
+
+        tx.commit()
+            .await
+            .map_err(backend("Couldn't commit Import"))?;
+        Ok(Imported {
+            entries: imported.len().try_into().unwrap_or(i64::MAX),
+            runs: runs.len().try_into().unwrap_or(i64::MAX),
+            skipped: skipped.len().try_into().unwrap_or(i64::MAX),
+        })
+    }
+}
@@ -0,0 +1,819 @@
+use crate::{
+    boards, build,
+    store::{Memory, Postgres, Store},
+};
+use rocket::{
+    http::{ContentType, Header, Status},
+    local::blocking::Client,
+};
+use utils::{
+    AuditRecord, Ban, Credentials, Difficulty, Entry, ErrorBody, ErrorKind, ExportedRun, Imported,
+    Mode, ModeratedEntry, Page, Period, PlayerName, PlayerRank, Recorded, Run, RunRecord,
+};
+
+const ADMIN_TOKEN: &str = "admin";
+
+fn client() -> Client {
+    let figment = rocket::Config::figment().merge((crate::ADMIN_TOKEN_KEY, ADMIN_TOKEN));
+    let rocket = build(Box::<Memory>::default(), figment).unwrap();
+    Client::tracked(rocket).unwrap()
+}
+
+fn admin() -> Header<'static> {
+    Header::new("Authorization", format!("Bearer {ADMIN_TOKEN}"))
+}
+
+fn player_name(name: &str) -> PlayerName {
+    PlayerName { name: name.into() }
+}
+
+fn run(name: &str, score: i64) -> Run {
+    Run {
+        name: name.into(),
+        score,
+        level: 1,
+        duration_secs: 60,
+        client_version: "test".into(),
+        mode: Mode::default(),
+        difficulty: Difficulty::default(),
+    }
+}
+
+fn register(client: &Client, name: &str) -> Credentials {
+    let response = client
+        .post("/api/v1/players")
+        .json(&player_name(name))
+        .dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    response.into_json().unwrap()
+}
+
+fn bearer(credentials: &Credentials) -> Header<'static> {
+    Header::new("Authorization", format!("Bearer {}", credentials.token))
+}
+
+fn submit(client: &Client, credentials: &Credentials, score: i64) -> Status {
+    client
+        .post("/api/v1/runs")
+        .header(bearer(credentials))
+        .json(&run(&credentials.name, score))
+        .dispatch()
+        .status()
+}
+
+fn entries(client: &Client) -> Vec<Entry> {
+    client
+        .get("/api/v1/entries")
+        .dispatch()
+        .into_json()
+        .unwrap()
+}
+
+#[test]
+fn submitted_run_is_listed() {
+    let client = client();
+    let alice = register(&client, "alice");
+
+    assert_eq!(submit(&client, &alice, 120), Status::Ok);
+    assert_eq!(
+        entries(&client),
+        vec![Entry {
+            name: "alice".into(),
+            score: 120,
+            level: 1,
+            client_version: "test".into(),
+            mode: Mode::Classic,
+            difficulty: Difficulty::Normal,
+        }]
+    );
+
+    let page: Page = client.get("/api/v1/scores").dispatch().into_json().unwrap();
+    assert_eq!(page.total, 1);
+    assert_eq!(page.entries[0].rank, 1);
+}
+
+#[test]
+fn submission_needs_token() {
+    let client = client();
+    let response = client
+        .post("/api/v1/runs")
+        .json(&run("alice", 10))
+        .dispatch();
+
+    assert_eq!(response.status(), Status::Unauthorized);
+    assert!(entries(&client).is_empty());
+}
+
+#[test]
+fn lower_score_keeps_best() {
+    let client = client();
+    let alice = register(&client, "alice");
+
+    submit(&client, &alice, 200);
+    submit(&client, &alice, 50);
+
+    assert_eq!(entries(&client)[0].score, 200);
+    let runs: Vec<RunRecord> = client
+        .get("/api/v1/players/alice/runs")
+        .dispatch()
+        .into_json()
+        .unwrap();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].score, 50);
+}
+
+#[test]
+fn personal_best_is_reported() {
+    let client = client();
+    let alice = register(&client, "alice");
+    let recorded = |score| -> Recorded {
+        client
+            .post("/api/v1/runs")
+            .header(bearer(&alice))
+            .json(&run("alice", score))
+            .dispatch()
+            .into_json()
+            .unwrap()
+    };
+
+    assert_eq!(
+        recorded(100),
+        Recorded {
+            personal_best: true,
+            best: 100
+        }
+    );
+    assert_eq!(
+        recorded(60),
+        Recorded {
+            personal_best: false,
+            best: 100
+        }
+    );
+    // Equal Scores don't count as a new Best
+    assert!(!recorded(100).personal_best);
+    assert!(recorded(110).personal_best);
+}
+
+#[test]
+fn invalid_run_is_rejected() {
+    let client = client();
+    let alice = register(&client, "alice");
+
+    assert_eq!(submit(&client, &alice, -1), Status::UnprocessableEntity);
+    assert!(entries(&client).is_empty());
+}
+
+#[test]
+fn name_can_only_be_taken_once() {
+    let client = client();
+    register(&client, "alice");
+
+    let response = client
+        .post("/api/v1/players")
+        .json(&player_name("alice"))
+        .dispatch();
+    assert_eq!(response.status(), Status::Conflict);
+}
+
+#[test]
+fn rename_moves_runs() {
+    let client = client();
+    let alice = register(&client, "alice");
+    submit(&client, &alice, 70);
+
+    let response = client
+        .post("/api/v1/players/rename")
+        .header(bearer(&alice))
+        .json(&player_name("alicia"))
+        .dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    assert_eq!(entries(&client)[0].name, "alicia");
+    // The Token stays valid under the new Name
+    assert_eq!(submit(&client, &alice, 90), Status::Ok);
+    assert_eq!(entries(&client)[0].score, 90);
+}
+
+#[test]
+fn rename_to_taken_name_conflicts() {
+    let client = client();
+    let alice = register(&client, "alice");
+    register(&client, "bob");
+
+    let response = client
+        .post("/api/v1/players/rename")
+        .header(bearer(&alice))
+        .json(&player_name("bob"))
+        .dispatch();
+    assert_eq!(response.status(), Status::Conflict);
+}
+
+#[test]
+fn renames_leave_submissions_alone() {
+    let figment = rocket::Config::figment().merge(("abuse.submissions_per_minute", 1));
+    let rocket = build(Box::<Memory>::default(), figment).unwrap();
+    let client = Client::tracked(rocket).unwrap();
+    let alice = register(&client, "alice");
+
+    for name in ["alicia", "alice"] {
+        let response = client
+            .post("/api/v1/players/rename")
+            .header(bearer(&alice))
+            .json(&player_name(name))
+            .dispatch();
+        assert_eq!(response.status(), Status::Ok);
+    }
+    assert_eq!(submit(&client, &alice, 10), Status::Ok);
+    assert_eq!(submit(&client, &alice, 20), Status::TooManyRequests);
+}
+
+#[test]
+fn player_rank_has_neighbours() {
+    let client = client();
+    for (name, score) in [("a", 50), ("b", 40), ("c", 30), ("d", 20)] {
+        let credentials = register(&client, name);
+        submit(&client, &credentials, score);
+    }
+
+    let rank: PlayerRank = client
+        .get("/api/v1/players/c")
+        .dispatch()
+        .into_json()
+        .unwrap();
+    assert_eq!(rank.entry.rank, 3);
+    assert_eq!(rank.total, 4);
+    assert!((rank.percentile - 25.0).abs() < f64::EPSILON);
+    let neighbours: Vec<_> = rank.neighbours.iter().map(|e| e.name.as_str()).collect();
+    assert_eq!(neighbours, ["a", "b", "d"]);
+
+    let response = client.get("/api/v1/players/nobody").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+    let error: ErrorBody = response.into_json().unwrap();
+    assert_eq!(error.error, ErrorKind::NotFound);
+    assert_eq!(error.message, "nobody has no Entry");
+}
+
+#[test]
+fn equal_scores_share_rank() {
+    let client = client();
+    for name in ["a", "b"] {
+        let credentials = register(&client, name);
+        submit(&client, &credentials, 10);
+    }
+
+    let page: Page = client.get("/api/v1/scores").dispatch().into_json().unwrap();
+    let ranks: Vec<_> = page.entries.iter().map(|e| e.rank).collect();
+    assert_eq!(ranks, [1, 1]);
+}
+
+#[test]
+fn boards_contain_current_runs() {
+    let client = client();
+    let alice = register(&client, "alice");
+    submit(&client, &alice, 30);
+
+    for period in Period::ALL {
+        let page: Page = client
+            .get(format!("/api/v1/boards/{}", period.as_str()))
+            .dispatch()
+            .into_json()
+            .unwrap();
+        assert_eq!(page.total, 1, "{period:?}");
+    }
+
+    let response = client.get("/api/v1/boards/yearly").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+}
+
+#[test]
+fn boards_are_kept_per_mode_and_difficulty() {
+    let client = client();
+    let alice = register(&client, "alice");
+    submit(&client, &alice, 100);
+    let hard = Run {
+        mode: Mode::TimeAttack,
+        difficulty: Difficulty::Hard,
+        ..run("alice", 40)
+    };
+    let response = client
+        .post("/api/v1/runs")
+        .header(bearer(&alice))
+        .json(&hard)
+        .dispatch();
+    // A new Board starts a new personal Best
+    assert!(response.into_json::<Recorded>().unwrap().personal_best);
+
+    let board: Vec<Entry> = client
+        .get("/api/v1/entries?mode=time-attack&difficulty=hard")
+        .dispatch()
+        .into_json()
+        .unwrap();
+    assert_eq!(board.len(), 1);
+    assert_eq!((board[0].score, board[0].mode), (40, Mode::TimeAttack));
+    assert_eq!(entries(&client)[0].score, 100);
+
+    let page: Page = client
+        .get("/api/v1/boards/daily?mode=time-attack&difficulty=easy")
+        .dispatch()
+        .into_json()
+        .unwrap();
+    assert_eq!(page.total, 0);
+
+    let response = client.get("/api/v1/scores?mode=survival").dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+
+    let too_long = Run {
+        duration_secs: 10 * 60,
+        ..hard
+    };
+    let response = client
+        .post("/api/v1/runs")
+        .header(bearer(&alice))
+        .json(&too_long)
+        .dispatch();
+    assert_eq!(response.status(), Status::UnprocessableEntity);
+}
+
+#[test]
+fn deleted_player_is_gone() {
+    let client = client();
+    let alice = register(&client, "alice");
+    submit(&client, &alice, 30);
+
+    let response = client
+        .delete("/api/v1/players")
+        .header(bearer(&alice))
+        .dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    assert!(entries(&client).is_empty());
+    assert_eq!(submit(&client, &alice, 40), Status::Unauthorized);
+    // The Name is free again
+    register(&client, "alice");
+}
+
+#[rocket::async_test]
+async fn new_best_is_streamed() {
+    use rocket::{local::asynchronous::Client, tokio::io::AsyncReadExt};
+
+    let rocket = build(Box::<Memory>::default(), rocket::Config::figment()).unwrap();
+    let client = Client::tracked(rocket).await.unwrap();
+    let mut events = client.get("/api/v1/events").dispatch().await;
+
+    let alice: Credentials = client
+        .post("/api/v1/players")
+        .json(&player_name("alice"))
+        .dispatch()
+        .await
+        .into_json()
+        .await
+        .unwrap();
+    client
+        .post("/api/v1/runs")
+        .header(bearer(&alice))
+        .json(&run("alice", 90))
+        .dispatch()
+        .await;
+
+    // An Event ends with an empty Line
+    let mut event = String::new();
+    let mut buffer = [0; 256];
+    while !event.ends_with("\n\n") {
+        let read = events.read(&mut buffer).await.unwrap();
+        event.push_str(std::str::from_utf8(&buffer[..read]).unwrap());
+    }
+    assert!(event.starts_with("event:rank\n"), "{event}");
+    assert!(
+        event.contains(r#"data:{"rank":1,"name":"alice","score":90,"#),
+        "{event}"
+    );
+}
+
+#[test]
+fn unversioned_routes_still_work() {
+    let client = client();
+    let alice = register(&client, "alice");
+
+    let response = client
+        .post("/")
+        .header(bearer(&alice))
+        .json(&run("alice", 40))
+        .dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let legacy: Vec<Entry> = client.get("/").dispatch().into_json().unwrap();
+    assert_eq!(legacy, entries(&client));
+    let response = client.get("/players/alice").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+}
+
+#[test]
+fn openapi_lists_routes() {
+    let client = client();
+    let response = client.get("/api/v1/openapi.json").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    let document = response.into_string().unwrap();
+    for path in ["/runs", "/entries", "/boards/{period}", "/players/rename"] {
+        assert!(document.contains(&format!("\"{path}\"")), "{path}");
+    }
+}
+
+#[test]
+fn probes_report_ready() {
+    let client = client();
+    assert_eq!(client.get("/health").dispatch().status(), Status::Ok);
+    assert_eq!(client.get("/ready").dispatch().status(), Status::Ok);
+}
+
+#[test]
+fn metrics_count_submissions() {
+    let client = client();
+    let alice = register(&client, "alice");
+    assert_eq!(submit(&client, &alice, 100), Status::Ok);
+    assert_eq!(submit(&client, &alice, 15), Status::UnprocessableEntity);
+
+    let metrics = client.get("/metrics").dispatch().into_string().unwrap();
+    assert!(metrics.contains("submissions_total{result=\"accepted\"} 1"));
+    assert!(metrics.contains("submissions_total{result=\"rejected\"} 1"));
+    assert!(metrics
+        .contains("http_requests_total{method=\"POST\",route=\"/api/v1/runs\",status=\"200\"} 1"));
+}
+
+#[test]
+fn admin_routes_need_admin_token() {
+    let client = client();
+    let alice = register(&client, "alice");
+
+    let response = client.get("/api/v1/admin/entries").dispatch();
+    assert_eq!(response.status(), Status::Unauthorized);
+    let response = client
+        .get("/api/v1/admin/entries")
+        .header(bearer(&alice))
+        .dispatch();
+    assert_eq!(response.status(), Status::Unauthorized);
+    let error: ErrorBody = response.into_json().unwrap();
+    assert_eq!(error.message, "A valid Admin Token is required");
+}
+
+#[test]
+fn hidden_entry_leaves_leaderboards() {
+    let client = client();
+    let alice = register(&client, "alice");
+    submit(&client, &alice, 50);
+
+    let response = client
+        .post("/api/v1/admin/entries/alice/hide")
+        .header(admin())
+        .dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert!(entries(&client).is_empty());
+    let page: Page = client
+        .get("/api/v1/boards/daily")
+        .dispatch()
+        .into_json()
+        .unwrap();
+    assert_eq!(page.total, 0);
+
+    let moderated: Vec<ModeratedEntry> = client
+        .get("/api/v1/admin/entries")
+        .header(admin())
+        .dispatch()
+        .into_json()
+        .unwrap();
+    assert!(moderated[0].hidden);
+
+    client
+        .post("/api/v1/admin/entries/alice/show")
+        .header(admin())
+        .dispatch();
+    assert_eq!(entries(&client).len(), 1);
+
+    let log: Vec<AuditRecord> = client
+        .get("/api/v1/admin/audit")
+        .header(admin())
+        .dispatch()
+        .into_json()
+        .unwrap();
+    let actions: Vec<_> = log.iter().map(|r| r.action.as_str()).collect();
+    assert_eq!(actions, ["show", "hide"]);
+}
+
+/// Hides `name` and then submits it on a Board it had no Entry on yet
+async fn hidden_name_stays_hidden_on_new_boards(store: Store, name: &str) {
+    use rocket::local::asynchronous::Client;
+
+    let figment = rocket::Config::figment().merge((crate::ADMIN_TOKEN_KEY, ADMIN_TOKEN));
+    let client = Client::tracked(build(store, figment).unwrap())
+        .await
+        .unwrap();
+    let player: Credentials = client
+        .post("/api/v1/players")
+        .json(&player_name(name))
+        .dispatch()
+        .await
+        .into_json()
+        .await
+        .unwrap();
+    let submit = |score, difficulty| {
+        client
+            .post("/api/v1/runs")
+            .header(bearer(&player))
+            .json(&Run {
+                difficulty,
+                ..run(name, score)
+            })
+            .dispatch()
+    };
+    let total = || async {
+        let page: Page = client
+            .get("/api/v1/boards/daily?difficulty=easy")
+            .dispatch()
+            .await
+            .into_json()
+            .await
+            .unwrap();
+        page.total
+    };
+
+    submit(50, Difficulty::Normal).await;
+    let response = client
+        .post(format!("/api/v1/admin/entries/{name}/hide"))
+        .header(admin())
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let before = total().await;
+    assert_eq!(submit(70, Difficulty::Easy).await.status(), Status::Ok);
+
+    let board: Vec<Entry> = client
+        .get("/api/v1/entries?difficulty=easy")
+        .dispatch()
+        .await
+        .into_json()
+        .await
+        .unwrap();
+    assert!(board.iter().all(|entry| entry.name != name));
+    assert_eq!(total().await, before);
+    let response = client
+        .get(format!("/api/v1/players/{name}?difficulty=easy"))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::NotFound);
+
+    client
+        .post(format!("/api/v1/admin/entries/{name}/show"))
+        .header(admin())
+        .dispatch()
+        .await;
+    assert_eq!(total().await, before + 1);
+    let response = client
+        .get(format!("/api/v1/players/{name}?difficulty=easy"))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+
+    client
+        .delete(format!("/api/v1/admin/entries/{name}"))
+        .header(admin())
+        .dispatch()
+        .await;
+    client
+        .delete("/api/v1/players")
+        .header(bearer(&player))
+        .dispatch()
+        .await;
+}
+
+#[rocket::async_test]
+async fn hidden_name_stays_hidden_in_memory() {
+    hidden_name_stays_hidden_on_new_boards(Box::<Memory>::default(), "alice").await;
+}
+
+/// Needs a Postgres Database at `DATABASE_URL`, which it migrates
+#[rocket::async_test]
+#[ignore = "needs DATABASE_URL"]
+async fn hidden_name_stays_hidden_in_postgres() {
+    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL isn't set");
+    let pool = sqlx::PgPool::connect(&url).await.unwrap();
+    let store = Postgres::migrate(pool).await.unwrap();
+    // Leaves other Names in the Database alone
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .subsec_nanos();
+    let name = format!("hidden-{nanos}");
+    hidden_name_stays_hidden_on_new_boards(Box::new(store), &name).await;
+}
+
+#[test]
+fn banned_player_cannot_submit() {
+    let client = client();
+    let alice = register(&client, "alice");
+    submit(&client, &alice, 50);
+
+    let response = client
+        .post("/api/v1/admin/bans/alice")
+        .header(admin())
+        .json(&Ban {
+            reason: "Cheating".into(),
+        })
+        .dispatch();
+    assert_eq!(response.status(), Status::Ok);
+
+    assert_eq!(submit(&client, &alice, 60), Status::Forbidden);
+    assert!(entries(&client).is_empty());
+
+    let response = client
+        .delete("/api/v1/admin/bans/alice")
+        .header(admin())
+        .dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(submit(&client, &alice, 60), Status::Ok);
+}
+
+#[test]
+fn requests_over_the_limit_are_refused() {
+    let figment = rocket::Config::figment().merge(("abuse.requests_per_minute", 2));
+    let rocket = build(Box::<Memory>::default(), figment).unwrap();
+    let client = Client::tracked(rocket).unwrap();
+    let get = || {
+        client
+            .get("/api/v1/entries")
+            .remote("192.0.2.1:4000".parse().unwrap())
+    };
+
+    for _ in 0..2 {
+        assert_eq!(get().dispatch().status(), Status::Ok);
+    }
+    // A forged Header doesn't count as another Client
+    let response = get()
+        .header(Header::new("X-Real-IP", "203.0.113.7"))
+        .dispatch();
+    assert_eq!(response.status(), Status::TooManyRequests);
+    let retry_after: u64 = response
+        .headers()
+        .get_one("Retry-After")
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!((1..=60).contains(&retry_after));
+    let body: ErrorBody = response.into_json().unwrap();
+    assert_eq!(body.error, ErrorKind::RateLimited);
+}
+
+#[test]
+fn admin_renames_and_deletes_entries() {
+    let client = client();
+    let alice = register(&client, "alice");
+    submit(&client, &alice, 50);
+
+    let response = client
+        .post("/api/v1/admin/entries/alice/rename")
+        .header(admin())
+        .json(&player_name("player1"))
+        .dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(entries(&client)[0].name, "player1");
+    // The Player keeps their Token
+    assert_eq!(submit(&client, &alice, 60), Status::Ok);
+
+    let delete = || {
+        client
+            .delete("/api/v1/admin/entries/player1")
+            .header(admin())
+            .dispatch()
+            .status()
+    };
+    assert_eq!(delete(), Status::Ok);
+    assert!(entries(&client).is_empty());
+    assert_eq!(delete(), Status::NotFound);
+}
+
+fn export(client: &Client, format: &str) -> String {
+    let response = client
+        .get(format!("/api/v1/admin/export?format={format}"))
+        .header(admin())
+        .dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    response.into_string().unwrap()
+}
+
+#[test]
+fn backup_restores_leaderboard() {
+    let source = client();
+    let alice = register(&source, "alice");
+    submit(&source, &alice, 50);
+    submit(&source, &alice, 20);
+    let bob = register(&source, "bob");
+    submit(&source, &bob, 30);
+    source
+        .post("/api/v1/admin/entries/bob/hide")
+        .header(admin())
+        .dispatch();
+
+    let json = export(&source, "json");
+    let csv = export(&source, "csv");
+    assert_eq!(csv.lines().count(), 1 + 3);
+    assert!(csv.starts_with("name,score,level,"));
+
+    for (content_type, backup) in [(ContentType::JSON, json), (ContentType::CSV, csv)] {
+        let target = client();
+        let response = target
+            .post("/api/v1/admin/import")
+            .header(admin())
+            .header(content_type)
+            .body(backup)
+            .dispatch();
+        assert_eq!(response.status(), Status::Ok);
+        let imported: Imported = response.into_json().unwrap();
+        assert_eq!((imported.entries, imported.runs), (2, 3));
+
+        assert_eq!(entries(&target), entries(&source));
+        let runs: Vec<RunRecord> = target
+            .get("/api/v1/players/alice/runs")
+            .dispatch()
+            .into_json()
+            .unwrap();
+        assert_eq!(runs.len(), 2);
+    }
+}
+
+#[test]
+fn import_handles_conflicts() {
+    let client = client();
+    let alice = register(&client, "alice");
+    submit(&client, &alice, 50);
+    let backup: Vec<ExportedRun> = client
+        .get("/api/v1/admin/export")
+        .header(admin())
+        .dispatch()
+        .into_json()
+        .unwrap();
+    submit(&client, &alice, 70);
+
+    let import = |on_conflict: &str| {
+        client
+            .post(format!("/api/v1/admin/import?on_conflict={on_conflict}"))
+            .header(admin())
+            .json(&backup)
+            .dispatch()
+    };
+
+    let response = import("skip");
+    assert_eq!(response.status(), Status::Ok);
+    let imported: Imported = response.into_json().unwrap();
+    assert_eq!((imported.runs, imported.skipped), (0, 1));
+    assert_eq!(entries(&client)[0].score, 70);
+
+    assert_eq!(import("fail").status(), Status::Conflict);
+    assert_eq!(entries(&client)[0].score, 70);
+
+    assert_eq!(import("overwrite").status(), Status::Ok);
+    assert_eq!(entries(&client)[0].score, 50);
+
+    assert_eq!(import("merge").status(), Status::UnprocessableEntity);
+}
+
+#[test]
+fn oversized_import_is_too_large() {
+    let figment = rocket::Config::figment()
+        .merge((crate::ADMIN_TOKEN_KEY, ADMIN_TOKEN))
+        .merge(("limits.csv", 64));
+    let rocket = build(Box::<Memory>::default(), figment).unwrap();
+    let client = Client::tracked(rocket).unwrap();
+
+    let alice = register(&client, "alice");
+    submit(&client, &alice, 50);
+    let csv = export(&client, "csv");
+    assert!(csv.len() > 64);
+
+    let response = client
+        .post("/api/v1/admin/import")
+        .header(admin())
+        .header(ContentType::CSV)
+        .body(csv)
+        .dispatch();
+    assert_eq!(response.status(), Status::PayloadTooLarge);
+    let body: ErrorBody = response.into_json().unwrap();
+    assert_eq!(body.error, ErrorKind::TooLarge);
+}
+
+#[test]
+fn periods_start_in_utc() {
+    // Thursday, 2024-02-29 12:00 UTC
+    let now = 1_709_208_000;
+    assert_eq!(boards::start(Period::Daily, now), Some(1_709_164_800));
+    assert_eq!(boards::start(Period::Weekly, now), Some(1_708_905_600));
+    assert_eq!(boards::start(Period::Monthly, now), Some(1_706_745_600));
+    assert_eq!(boards::start(Period::AllTime, now), None);
+
+    // Wednesday, 1969-12-31 23:00 UTC
+    let before_epoch = -3600;
+    assert_eq!(boards::start(Period::Weekly, before_epoch), Some(-259_200));
+    assert_eq!(
+        boards::start(Period::Monthly, before_epoch),
+        Some(-2_678_400)
+    );
+}
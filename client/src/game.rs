@@ -1,9 +1,10 @@
-use crate::AppState;
+use crate::{rules::Rules, AppState};
 use bevy::{app, ecs::schedule, prelude::*, time::Stopwatch};
 use bevy_rand::prelude::*;
 use leafwing_input_manager::prelude::*;
 use player::actions::Action;
 use std::fmt;
+use utils::Mode;
 
 mod cell;
 mod enemy;
@@ -74,7 +75,10 @@ impl app::Plugin for Plugin {
             .add_systems(OnEnter(State::LvlStartup), to_play_state)
             .add_systems(
                 Update,
-                toggle_pause.run_if(in_state(State::Playing).or_else(in_state(State::Paused))),
+                (
+                    toggle_pause.run_if(in_state(State::Playing).or_else(in_state(State::Paused))),
+                    time_up.run_if(in_state(State::Playing)),
+                ),
             );
     }
 }
@@ -147,6 +151,13 @@ impl Default for Board {
     }
 }
 
+/// Ends a Time Attack once its Time is up
+fn time_up((rules, time, mut next_state): (Res<Rules>, Res<Time>, ResMut<NextState<State>>)) {
+    if rules.mode == Mode::TimeAttack && time.elapsed().as_secs() >= Mode::TIME_LIMIT_SECS {
+        next_state.set(State::GameOver);
+    }
+}
+
 fn toggle_pause(
     (state, mut next_state): (Res<schedule::State<State>>, ResMut<NextState<State>>),
     input: Query<&ActionState<Action>>,
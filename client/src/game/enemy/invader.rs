@@ -5,6 +5,7 @@ use bevy_rand::prelude::*;
 use bevy_rapier2d::prelude::*;
 use movement::Delay;
 use std::{fmt, time::Duration};
+use utils::points::{CRAB, OCTOPUS, SQUID};
 
 mod movement;
 pub mod shooting;
@@ -50,9 +51,9 @@ type Dimensions = Vec2;
 type Points = usize;
 
 const TYPES: [(Type, Dimensions, Points, Color); 3] = [
-    (Type::Squid1, Vec2::new(16.0, 16.0), 30, Color::GREEN),
-    (Type::Crab1, Vec2::new(22.0, 16.0), 20, Color::YELLOW),
-    (Type::Octopus1, Vec2::new(24.0, 16.0), 10, Color::RED),
+    (Type::Squid1, Vec2::new(16.0, 16.0), SQUID, Color::GREEN),
+    (Type::Crab1, Vec2::new(22.0, 16.0), CRAB, Color::YELLOW),
+    (Type::Octopus1, Vec2::new(24.0, 16.0), OCTOPUS, Color::RED),
 ];
 const ROWS_TO_POPULATE: usize = 5;
 const ROWS_TO_SKIP: usize = 2;
@@ -1,4 +1,7 @@
-use crate::game::{self, enemy::Enemy, projectile};
+use crate::{
+    game::{self, enemy::Enemy, projectile},
+    rules::Rules,
+};
 use bevy::{app, prelude::*};
 use bevy_rand::prelude::*;
 use bevy_rapier2d::prelude::*;
@@ -21,19 +24,13 @@ impl app::Plugin for Plugin {
     }
 }
 
-fn setup(mut commands: Commands) {
-    commands.insert_resource(Probability::default());
+fn setup(mut commands: Commands, rules: Res<Rules>) {
+    commands.insert_resource(Probability(probability::first_level(rules.difficulty)));
 }
 
 #[derive(Resource, Deref, DerefMut)]
 pub struct Probability(pub f64);
 
-impl Default for Probability {
-    fn default() -> Self {
-        Self(probability::LOWEST)
-    }
-}
-
 impl Probability {
     pub fn increase(&mut self) {
         let new_prob = self.0 + probability::INCREMENT_RATE;
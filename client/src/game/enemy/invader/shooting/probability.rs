@@ -1,3 +1,14 @@
+use utils::Difficulty;
+
 pub(super) const INCREMENT_RATE: f64 = 0.1;
-pub(super) const LOWEST: f64 = 0.3;
+pub(super) const LOWEST: f64 = 0.2;
 pub(super) const HIGHEST: f64 = 1.0;
+
+/// Probability the first Level starts with
+pub(super) const fn first_level(difficulty: Difficulty) -> f64 {
+    match difficulty {
+        Difficulty::Easy => LOWEST,
+        Difficulty::Normal => 0.3,
+        Difficulty::Hard => 0.5,
+    }
+}
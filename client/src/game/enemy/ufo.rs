@@ -8,6 +8,7 @@ use bevy_rand::prelude::*;
 use bevy_rapier2d::prelude::*;
 use rand::Rng;
 use std::cmp::Ordering;
+use utils::points;
 
 const DIMENSIONS: Vec2 = Vec2::new(48.0, 21.0);
 
@@ -15,7 +16,7 @@ const DIMENSIONS: Vec2 = Vec2::new(48.0, 21.0);
 struct OGVelocity(Velocity);
 
 const SPEED: f32 = 200.0;
-const POINTS: [usize; 5] = [50, 100, 150, 200, 300];
+const POINTS: [usize; 5] = points::UFO;
 
 const X_OFFSET: f32 = window::DIMENSIONS.x / 2.0 + DIMENSIONS.x;
 const SECONDS_TILL_SPAWN: f32 = 10.0;
@@ -1,4 +1,6 @@
+use crate::game;
 use bevy::{app, prelude::*};
+use utils::Recorded;
 
 mod game_over;
 mod leaderboard;
@@ -8,9 +10,21 @@ pub struct Plugin;
 impl app::Plugin for Plugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<GuiData>()
-            .add_plugins((game_over::Plugin, leaderboard::Plugin));
+            .add_plugins((game_over::Plugin, leaderboard::Plugin))
+            .add_systems(OnEnter(game::State::Setup), reset_submission);
     }
 }
 
+/// The Submission of the current Game's Score
+#[derive(Resource)]
+struct Submitted {
+    name: String,
+    recorded: Recorded,
+}
+
+fn reset_submission(mut commands: Commands) {
+    commands.remove_resource::<Submitted>();
+}
+
 #[derive(Resource, Default, Deref, DerefMut)]
 struct GuiData(Vec<Entity>);
@@ -1,26 +1,34 @@
-use super::GuiData;
+use super::{GuiData, Submitted};
 use crate::{
     game,
-    menu::{button, FONT_SIZE, TEXT_COLOR},
+    menu::{button, focus, FONT_SIZE, TEXT_COLOR},
+    net, rules,
 };
 use bevy::{
     app::{self, AppExit},
     prelude::*,
 };
+use utils::{PlayerRank, Recorded};
 
 pub struct Plugin;
 
 impl app::Plugin for Plugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(game::State::GameOver), setup)
-            .add_systems(Update, game_over.run_if(in_state(game::State::GameOver)))
+            .add_systems(
+                Update,
+                (game_over, placement).run_if(in_state(game::State::GameOver)),
+            )
             .add_systems(OnExit(game::State::GameOver), cleanup);
     }
 }
 
 fn cleanup(mut commands: Commands, mut menu_data: ResMut<GuiData>) {
+    // Finished Requests have already despawned themselves
     for entity in menu_data.drain(..) {
-        commands.entity(entity).despawn_recursive();
+        if let Some(entity) = commands.get_entity(entity) {
+            entity.despawn_recursive();
+        }
     }
 }
 
@@ -31,7 +39,16 @@ enum Buttons {
     Quit,
 }
 
-fn setup(mut commands: Commands, (mut gui_data, score): (ResMut<GuiData>, Res<game::Score>)) {
+fn setup(
+    mut commands: Commands,
+    (mut gui_data, score, server, rules, submitted): (
+        ResMut<GuiData>,
+        Res<game::Score>,
+        Res<net::Server>,
+        Res<rules::Rules>,
+        Option<Res<Submitted>>,
+    ),
+) {
     let ui_entity = commands
         .spawn((
             Name::new("Game Over UI Node"),
@@ -51,12 +68,27 @@ fn setup(mut commands: Commands, (mut gui_data, score): (ResMut<GuiData>, Res<ga
         ))
         .with_children(setup_header)
         .with_children(|parent| setup_score(parent, score))
+        .with_children(|parent| {
+            if submitted.is_some() {
+                setup_placement(parent);
+            }
+        })
         .with_children(setup_restart)
         .with_children(setup_leaderboard)
         .with_children(setup_quit)
         .id();
 
     gui_data.push(ui_entity);
+
+    if let Some(submitted) = submitted {
+        let request = commands
+            .spawn((
+                Name::new("Placement Request"),
+                net::player(&server, &submitted.name, *rules),
+            ))
+            .id();
+        gui_data.push(request);
+    }
 }
 
 fn setup_header(parent: &mut ChildBuilder) {
@@ -97,11 +129,62 @@ fn setup_score(parent: &mut ChildBuilder, res: Res<game::Score>) {
     ));
 }
 
+#[derive(Component)]
+struct PlacementText;
+
+fn setup_placement(parent: &mut ChildBuilder) {
+    parent.spawn((
+        Name::new("Placement"),
+        PlacementText,
+        TextBundle::from_section(
+            "Fetching your Placement...",
+            TextStyle {
+                font_size: FONT_SIZE,
+                color: TEXT_COLOR,
+                ..default()
+            },
+        ),
+    ));
+}
+
+fn placement(
+    mut commands: Commands,
+    submitted: Option<Res<Submitted>>,
+    (mut requests, mut text): (
+        Query<(Entity, &mut net::Request<PlayerRank>)>,
+        Query<&mut Text, With<PlacementText>>,
+    ),
+) {
+    for (request_entity, mut request) in &mut requests {
+        let Some(response) = request.poll() else {
+            continue;
+        };
+        commands.entity(request_entity).despawn();
+
+        let placed = match response {
+            Ok(PlayerRank { entry, total, .. }) => format!("You placed #{} of {total}", entry.rank),
+            Err(e) => format!("Couldn't fetch your Placement: {e}"),
+        };
+        let value = match submitted.as_deref().map(|s| s.recorded) {
+            Some(Recorded {
+                personal_best: true,
+                ..
+            }) => format!("New personal Best!\n{placed}"),
+            Some(Recorded { best, .. }) => format!("{placed}\nYour Best: {best}"),
+            None => placed,
+        };
+        for mut text in &mut text {
+            text.sections[0].value.clone_from(&value);
+        }
+    }
+}
+
 fn setup_restart(parent: &mut ChildBuilder) {
     parent
         .spawn((
             Name::new("Restart Button"),
             Buttons::Restart,
+            focus::Focusable,
             ButtonBundle {
                 style: Style {
                     min_width: button::size::MIN_WIDTH,
@@ -112,7 +195,6 @@ fn setup_restart(parent: &mut ChildBuilder) {
                     align_items: AlignItems::Center,
                     ..default()
                 },
-                background_color: BackgroundColor(Color::BLACK),
                 ..default()
             },
         ))
@@ -133,6 +215,7 @@ fn setup_leaderboard(parent: &mut ChildBuilder) {
         .spawn((
             Name::new("Leaderboard Button"),
             Buttons::LeaderBoard,
+            focus::Focusable,
             ButtonBundle {
                 style: Style {
                     min_width: button::size::MIN_WIDTH,
@@ -163,6 +246,7 @@ fn setup_quit(parent: &mut ChildBuilder) {
         .spawn((
             Name::new("Quit Button"),
             Buttons::Quit,
+            focus::Focusable,
             ButtonBundle {
                 style: Style {
                     min_width: button::size::MIN_WIDTH,
@@ -190,26 +274,19 @@ fn setup_quit(parent: &mut ChildBuilder) {
 
 fn game_over(
     (mut next_state, mut exit): (ResMut<NextState<game::State>>, EventWriter<AppExit>),
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &Buttons),
-        (Changed<Interaction>, With<Button>),
-    >,
+    mut activated: EventReader<focus::Activated>,
+    buttons: Query<&Buttons>,
 ) {
-    for (interaction, mut color, button_type) in &mut interaction_query {
-        *color = match interaction {
-            Interaction::Pressed => {
-                match button_type {
-                    Buttons::Restart => next_state.set(game::State::Setup),
-                    Buttons::LeaderBoard => next_state.set(game::State::Leaderboard),
-                    Buttons::Quit => {
-                        exit.send_default();
-                    }
-                }
-                button::color::PRESSED
+    for button_type in activated
+        .read()
+        .filter_map(|&focus::Activated(entity)| buttons.get(entity).ok())
+    {
+        match button_type {
+            Buttons::Restart => next_state.set(game::State::Setup),
+            Buttons::LeaderBoard => next_state.set(game::State::Leaderboard),
+            Buttons::Quit => {
+                exit.send_default();
             }
-            Interaction::Hovered => button::color::HOVERED,
-            Interaction::None => button::color::NORMAL,
         }
-        .into();
     }
 }
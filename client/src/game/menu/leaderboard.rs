@@ -1,44 +1,83 @@
-use super::GuiData;
+use super::{GuiData, Submitted};
 use crate::{
     game,
-    menu::{button, FONT_SIZE, TEXT_COLOR},
+    menu::{button, focus, FONT_SIZE, TEXT_COLOR},
+    net, rules,
 };
 use bevy::{app, prelude::*};
-use bevy_simple_text_input::{TextInputBundle, TextInputPlugin, TextInputSubmitEvent};
-use utils::Entry;
+use bevy_simple_text_input::{
+    TextInputBundle, TextInputPlugin, TextInputSubmitEvent, TextInputValue,
+};
+use leafwing_input_manager::prelude::*;
+use utils::{validation, Page, Period, Recorded, Run};
 
-const HOST_ADDRESS: &str = "https://bvspcinv-backend.shuttleapp.rs/";
 const TOP_N_SCORES: usize = 8;
+const KEYBOARD_ROWS: [&str; 4] = ["1234567890", "QWERTYUIOP", "ASDFGHJKL", "ZXCVBNM"];
 
 pub struct Plugin;
 
 impl app::Plugin for Plugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(TextInputPlugin)
+            .init_resource::<Board>()
             .add_systems(
                 OnEnter(game::State::Leaderboard),
-                (setup, display_scores).chain(),
+                (
+                    (setup, (fetch_scores, focus_name_input)).chain(),
+                    flush_pending,
+                ),
             )
             .add_systems(
                 Update,
-                (leaderboard, name_input).run_if(in_state(game::State::Leaderboard)),
+                (
+                    leaderboard,
+                    fetch_scores.run_if(
+                        resource_changed::<Board>.or_else(on_event::<net::live::RankChanged>()),
+                    ),
+                    update_tabs,
+                    keyboard,
+                    name_input,
+                    display_scores,
+                    submitted,
+                    update_status,
+                    update_pending,
+                )
+                    .run_if(in_state(game::State::Leaderboard)),
             )
             .add_systems(OnExit(game::State::Leaderboard), cleanup);
     }
 }
 
 fn cleanup(mut commands: Commands, mut menu_data: ResMut<GuiData>) {
+    // Finished Requests have already despawned themselves
     for entity in menu_data.drain(..) {
-        commands.entity(entity).despawn_recursive();
+        if let Some(entity) = commands.get_entity(entity) {
+            entity.despawn_recursive();
+        }
     }
 }
 
 #[derive(Component)]
 enum Buttons {
+    Tab(Period),
     Back,
 }
 
-fn setup(mut commands: Commands, mut gui_data: ResMut<GuiData>) {
+/// The Board that is shown. Kept when leaving the Leaderboard.
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Board(Period);
+
+fn setup(
+    mut commands: Commands,
+    (mut gui_data, server, rules, submitted): (
+        ResMut<GuiData>,
+        Res<net::Server>,
+        Res<rules::Rules>,
+        Option<Res<Submitted>>,
+    ),
+) {
+    // A Game is only submitted once, afterwards its Leaderboard can only be browsed
+    let submitting = submitted.is_none();
     let ui_entity = commands
         .spawn((
             Name::new("Leaderboard UI Node"),
@@ -56,49 +95,165 @@ fn setup(mut commands: Commands, mut gui_data: ResMut<GuiData>) {
                 ..default()
             },
         ))
-        .with_children(setup_header)
-        .with_children(setup_name_input)
-        .with_children(setup_leaderboard)
+        .with_children(|parent| setup_header(parent, *rules))
+        .with_children(setup_tabs)
+        .with_children(|parent| {
+            if submitting {
+                setup_name_input(parent);
+            }
+        })
+        .with_children(setup_status)
+        .with_children(setup_pending)
+        .with_children(|parent| setup_body(parent, submitting))
         .with_children(setup_back)
+        .with_children(|parent| setup_server(parent, server))
         .id();
 
     gui_data.push(ui_entity);
 }
 
+fn setup_body(parent: &mut ChildBuilder, submitting: bool) {
+    parent
+        .spawn((
+            Name::new("Leaderboard Body"),
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(setup_leaderboard)
+        .with_children(|parent| {
+            if submitting {
+                setup_keyboard(parent);
+            }
+        });
+}
+
 #[derive(Component)]
 enum LeaderboardMarker {
     Player,
     Score,
 }
 
-fn display_scores(mut commands: Commands, root_ui: Query<(Entity, &LeaderboardMarker)>) {
-    let Ok(response) = reqwest::blocking::get(HOST_ADDRESS) else {
-        return;
-    };
-    let mut leaderboard = response.json::<Vec<Entry>>().unwrap();
-
-    leaderboard.sort_unstable_by(|s1, s2| s2.score.cmp(&s1.score));
-    leaderboard.truncate(TOP_N_SCORES);
-    for (root_entity, marker) in &root_ui {
-        commands.entity(root_entity).despawn_descendants();
-        for entry in &leaderboard {
-            commands.entity(root_entity).with_children(|parent| {
-                parent.spawn(TextBundle::from_section(
-                    match marker {
-                        LeaderboardMarker::Player => entry.name.clone(),
-                        LeaderboardMarker::Score => format!("{} ", entry.score),
-                    },
-                    TextStyle {
-                        font_size: FONT_SIZE,
-                        color: TEXT_COLOR,
-                        ..default()
-                    },
-                ));
-            });
+fn fetch_scores(
+    mut commands: Commands,
+    (mut gui_data, server, board, rules): (
+        ResMut<GuiData>,
+        Res<net::Server>,
+        Res<Board>,
+        Res<rules::Rules>,
+    ),
+    (requests, mut status): (Query<Entity, With<net::Request<Page>>>, Query<&mut Status>),
+) {
+    // Answers for a previously selected Board are no longer wanted
+    for entity in &requests {
+        commands.entity(entity).despawn();
+    }
+
+    let request = commands
+        .spawn((
+            Name::new("Leaderboard Request"),
+            net::board(&server, **board, *rules, TOP_N_SCORES),
+        ))
+        .id();
+    gui_data.push(request);
+
+    for mut status in &mut status {
+        *status = Status::Loading("Loading Scores");
+    }
+}
+
+fn display_scores(
+    mut commands: Commands,
+    (mut requests, mut status): (Query<(Entity, &mut net::Request<Page>)>, Query<&mut Status>),
+    root_ui: Query<(Entity, &LeaderboardMarker)>,
+) {
+    for (request_entity, mut request) in &mut requests {
+        let Some(response) = request.poll() else {
+            continue;
+        };
+        commands.entity(request_entity).despawn();
+
+        let leaderboard = match response {
+            Ok(page) => page.entries,
+            Err(e) => {
+                for mut status in &mut status {
+                    *status = Status::Failed(format!("Couldn't load Scores: {e}"));
+                }
+                continue;
+            }
+        };
+        for mut status in &mut status {
+            *status = Status::Idle;
+        }
+
+        for (root_entity, marker) in &root_ui {
+            commands.entity(root_entity).despawn_descendants();
+            for entry in &leaderboard {
+                commands.entity(root_entity).with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        match marker {
+                            LeaderboardMarker::Player => format!("{}. {}", entry.rank, entry.name),
+                            LeaderboardMarker::Score => format!("{} ", entry.score),
+                        },
+                        TextStyle {
+                            font_size: FONT_SIZE,
+                            color: TEXT_COLOR,
+                            ..default()
+                        },
+                    ));
+                });
+            }
         }
     }
 }
 
+#[derive(Component, Default)]
+enum Status {
+    #[default]
+    Idle,
+    Loading(&'static str),
+    Failed(String),
+}
+
+fn setup_status(parent: &mut ChildBuilder) {
+    parent.spawn((
+        Name::new("Leaderboard Status"),
+        Status::default(),
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: FONT_SIZE / 2.0,
+                color: TEXT_COLOR,
+                ..default()
+            },
+        ),
+    ));
+}
+
+fn update_status(time: Res<Time>, mut query: Query<(&Status, &mut Text)>) {
+    const SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+    const FRAMES_PER_SECOND: f32 = 8.0;
+
+    for (status, mut text) in &mut query {
+        let section = &mut text.sections[0];
+        (section.value, section.style.color) = match status {
+            Status::Idle => (String::new(), TEXT_COLOR),
+            Status::Loading(message) => {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let frame = (time.elapsed_seconds() * FRAMES_PER_SECOND) as usize % SPINNER.len();
+                (format!("{message} {}", SPINNER[frame]), TEXT_COLOR)
+            }
+            Status::Failed(message) => (message.clone(), Color::RED),
+        };
+    }
+}
+
 fn setup_leaderboard(parent: &mut ChildBuilder) {
     parent
         .spawn(NodeBundle {
@@ -166,7 +321,7 @@ fn setup_leaderboard(parent: &mut ChildBuilder) {
         });
 }
 
-fn setup_header(parent: &mut ChildBuilder) {
+fn setup_header(parent: &mut ChildBuilder, rules: rules::Rules) {
     parent
         .spawn((
             Name::new("Leaderboard Header"),
@@ -182,7 +337,7 @@ fn setup_header(parent: &mut ChildBuilder) {
         ))
         .with_children(|parent| {
             parent.spawn(TextBundle::from_section(
-                "Leaderboard",
+                format!("Leaderboard: {}", rules.label()),
                 TextStyle {
                     font_size: FONT_SIZE,
                     color: TEXT_COLOR,
@@ -192,10 +347,82 @@ fn setup_header(parent: &mut ChildBuilder) {
         });
 }
 
+const fn tab_label(period: Period) -> &'static str {
+    match period {
+        Period::Daily => "Today",
+        Period::Weekly => "This Week",
+        Period::Monthly => "This Month",
+        Period::AllTime => "All Time",
+    }
+}
+
+fn setup_tabs(parent: &mut ChildBuilder) {
+    parent
+        .spawn((
+            Name::new("Leaderboard Tabs"),
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            for period in Period::ALL {
+                parent
+                    .spawn((
+                        Name::new(format!("Tab {}", tab_label(period))),
+                        Buttons::Tab(period),
+                        focus::Focusable,
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(150.0),
+                                height: Val::Px(40.0),
+                                border: UiRect::all(Val::Px(2.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            ..default()
+                        },
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            tab_label(period),
+                            TextStyle {
+                                font_size: FONT_SIZE / 2.0,
+                                color: TEXT_COLOR,
+                                ..default()
+                            },
+                        ));
+                    });
+            }
+        });
+}
+
+/// Outlines the Tab of the shown Board
+fn update_tabs(board: Res<Board>, mut tabs: Query<(&Buttons, &mut BorderColor)>) {
+    for (button_type, mut border) in &mut tabs {
+        if let Buttons::Tab(period) = *button_type {
+            *border = if period == **board {
+                TEXT_COLOR.into()
+            } else {
+                Color::NONE.into()
+            };
+        }
+    }
+}
+
+/// Typing only reaches the Name Input while it is focused, so it can be left
+/// with the Arrow Keys like any Button
 fn setup_name_input(parent: &mut ChildBuilder) {
     const BORDER_COLOR_ACTIVE: Color = Color::rgb(0.75, 0.52, 1.0);
-    const BACKGROUND_COLOR: Color = Color::rgb(0.15, 0.15, 0.15);
     parent.spawn((
+        Name::new("Name Input"),
+        focus::Focusable,
+        Interaction::default(),
         NodeBundle {
             style: Style {
                 width: Val::Px(200.0),
@@ -204,43 +431,310 @@ fn setup_name_input(parent: &mut ChildBuilder) {
                 ..default()
             },
             border_color: BORDER_COLOR_ACTIVE.into(),
-            background_color: BACKGROUND_COLOR.into(),
             ..default()
         },
-        TextInputBundle::default().with_text_style(TextStyle {
-            font_size: FONT_SIZE,
-            color: TEXT_COLOR,
-            ..default()
-        }),
+        TextInputBundle::default()
+            .with_text_style(TextStyle {
+                font_size: FONT_SIZE,
+                color: TEXT_COLOR,
+                ..default()
+            })
+            .with_inactive(true),
     ));
 }
 
+/// Starts with the Name Input, so the Name can be typed right away
+fn focus_name_input(
+    mut focused: ResMut<focus::Focused>,
+    input: Query<Entity, With<TextInputValue>>,
+) {
+    if let Ok(input) = input.get_single() {
+        **focused = Some(input);
+    }
+}
+
+#[derive(Component, Clone, Copy)]
+enum Key {
+    Char(char),
+    Backspace,
+    Submit,
+}
+
+impl Key {
+    fn label(self) -> String {
+        match self {
+            Self::Char(c) => c.to_string(),
+            Self::Backspace => "Del".into(),
+            Self::Submit => "OK".into(),
+        }
+    }
+}
+
+fn setup_keyboard(parent: &mut ChildBuilder) {
+    parent
+        .spawn((
+            Name::new("On-Screen Keyboard"),
+            NodeBundle {
+                style: Style {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(4.0),
+                    margin: UiRect::all(Val::Px(20.0)),
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            let rows = KEYBOARD_ROWS
+                .iter()
+                .map(|row| row.chars().map(Key::Char).collect::<Vec<_>>())
+                .chain([vec![Key::Char('_'), Key::Backspace, Key::Submit]]);
+
+            for row in rows {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(4.0),
+                            ..default()
+                        },
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        for key in row {
+                            setup_key(parent, key);
+                        }
+                    });
+            }
+        });
+}
+
+fn setup_key(parent: &mut ChildBuilder, key: Key) {
+    let width = match key {
+        Key::Char(_) => Val::Px(36.0),
+        Key::Backspace | Key::Submit => Val::Px(76.0),
+    };
+    parent
+        .spawn((
+            Name::new(format!("Key {}", key.label())),
+            key,
+            focus::Focusable,
+            ButtonBundle {
+                style: Style {
+                    width,
+                    height: Val::Px(36.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                key.label(),
+                TextStyle {
+                    font_size: FONT_SIZE / 2.0,
+                    color: TEXT_COLOR,
+                    ..default()
+                },
+            ));
+        });
+}
+
+fn keyboard(
+    (mut activated, mut submit): (
+        EventReader<focus::Activated>,
+        EventWriter<TextInputSubmitEvent>,
+    ),
+    (keys, mut input): (Query<&Key>, Query<(Entity, &mut TextInputValue)>),
+) {
+    let Ok((input, mut value)) = input.get_single_mut() else {
+        return;
+    };
+
+    for key in activated
+        .read()
+        .filter_map(|&focus::Activated(entity)| keys.get(entity).ok())
+    {
+        match *key {
+            Key::Char(c) => {
+                if value.0.len() < validation::MAX_NAME_LEN {
+                    value.0.push(c);
+                }
+            }
+            Key::Backspace => {
+                value.0.pop();
+            }
+            Key::Submit => {
+                submit.send(TextInputSubmitEvent {
+                    entity: input,
+                    value: value.0.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn setup_server(parent: &mut ChildBuilder, server: Res<net::Server>) {
+    parent.spawn((
+        Name::new("Leaderboard Server"),
+        TextBundle::from_section(
+            format!("Server: {}", **server),
+            TextStyle {
+                font_size: FONT_SIZE / 2.0,
+                color: TEXT_COLOR,
+                ..default()
+            },
+        ),
+    ));
+}
+
+#[derive(Component)]
+struct Submission(Run);
+
 fn name_input(
-    (mut events, mut next_state, score): (
+    mut commands: Commands,
+    (mut events, mut gui_data, server, rules): (
         EventReader<TextInputSubmitEvent>,
-        ResMut<NextState<game::State>>,
-        Res<game::Score>,
+        ResMut<GuiData>,
+        Res<net::Server>,
+        Res<rules::Rules>,
     ),
+    (score, level, played): (Res<game::Score>, Res<game::level::Level>, Res<game::Time>),
+    (submissions, mut status): (Query<(), With<Submission>>, Query<&mut Status>),
 ) {
-    for event in events.read().filter(|e| !e.value.is_empty()) {
-        let response = reqwest::blocking::Client::new()
-            .post(HOST_ADDRESS)
-            .body(format!(
-                "{{ \"name\": \"{}\", \"score\": {} }}",
-                event.value, score.0
+    // The Submission spawned for an earlier Event isn't visible yet
+    let mut submitting = !submissions.is_empty();
+    for event in events.read() {
+        if submitting {
+            continue;
+        }
+
+        if let Err(e) = validation::name(&event.value) {
+            for mut status in &mut status {
+                *status = Status::Failed(e.to_string());
+            }
+            continue;
+        }
+
+        let run = Run {
+            name: event.value.clone(),
+            score: i64::try_from(score.0).unwrap_or(i64::MAX),
+            level: i32::try_from(**level).unwrap_or(i32::MAX),
+            duration_secs: i32::try_from(played.elapsed().as_secs()).unwrap_or(i32::MAX),
+            client_version: env!("CARGO_PKG_VERSION").into(),
+            mode: rules.mode,
+            difficulty: rules.difficulty,
+        };
+        // The Backend would reject it anyway
+        if let Err(e) = validation::run(&run) {
+            for mut status in &mut status {
+                *status = Status::Failed(e.to_string());
+            }
+            continue;
+        }
+        let request = commands
+            .spawn((
+                Name::new("Score Submission"),
+                net::submit(&server, run.clone()),
+                Submission(run),
             ))
-            .send();
-        if response.is_ok() {
-            next_state.set(game::State::GameOver);
+            .id();
+        gui_data.push(request);
+        submitting = true;
+
+        for mut status in &mut status {
+            *status = Status::Loading("Submitting Score");
         }
     }
 }
 
+fn submitted(
+    mut commands: Commands,
+    (mut next_state, mut pending): (ResMut<NextState<game::State>>, ResMut<net::queue::Pending>),
+    (mut requests, mut status): (
+        Query<(Entity, &Submission, &mut net::Request<Recorded>)>,
+        Query<&mut Status>,
+    ),
+) {
+    for (request_entity, Submission(run), mut request) in &mut requests {
+        let Some(response) = request.poll() else {
+            continue;
+        };
+        commands.entity(request_entity).despawn();
+
+        match response {
+            Ok(recorded) => {
+                pending.remove(std::slice::from_ref(run));
+                commands.insert_resource(Submitted {
+                    name: run.name.clone(),
+                    recorded,
+                });
+                next_state.set(game::State::GameOver);
+            }
+            Err(e) if e.is_transient() => {
+                pending.push(run.clone());
+                for mut status in &mut status {
+                    *status = Status::Failed(format!(
+                        "Couldn't submit Score: {e}. It will be retried later."
+                    ));
+                }
+            }
+            Err(e) => {
+                for mut status in &mut status {
+                    *status = Status::Failed(format!("Couldn't submit Score: {e}"));
+                }
+            }
+        }
+    }
+}
+
+#[derive(Component)]
+struct PendingText;
+
+fn setup_pending(parent: &mut ChildBuilder) {
+    parent.spawn((
+        Name::new("Pending Submissions"),
+        PendingText,
+        TextBundle::from_section(
+            "",
+            TextStyle {
+                font_size: FONT_SIZE / 2.0,
+                color: Color::YELLOW,
+                ..default()
+            },
+        ),
+    ));
+}
+
+fn update_pending(
+    pending: Res<net::queue::Pending>,
+    mut query: Query<(&mut Text, Ref<PendingText>)>,
+) {
+    for (mut text, marker) in &mut query {
+        if !pending.is_changed() && !marker.is_added() {
+            continue;
+        }
+        text.sections[0].value = match pending.len() {
+            0 => String::new(),
+            1 => "1 Submission pending".into(),
+            n => format!("{n} Submissions pending"),
+        };
+    }
+}
+
+fn flush_pending(mut flush: EventWriter<net::queue::Flush>) {
+    flush.send_default();
+}
+
 fn setup_back(parent: &mut ChildBuilder) {
     parent
         .spawn((
             Name::new("Leaderboard Back Button"),
             Buttons::Back,
+            focus::Focusable,
             ButtonBundle {
                 style: Style {
                     min_width: button::size::MIN_WIDTH,
@@ -268,23 +762,24 @@ fn setup_back(parent: &mut ChildBuilder) {
 }
 
 fn leaderboard(
-    mut next_state: ResMut<NextState<game::State>>,
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &Buttons),
-        (Changed<Interaction>, With<Button>),
-    >,
+    (mut next_state, mut board): (ResMut<NextState<game::State>>, ResMut<Board>),
+    (actions, mut activated): (
+        Res<ActionState<focus::Action>>,
+        EventReader<focus::Activated>,
+    ),
+    buttons: Query<&Buttons>,
 ) {
-    for (interaction, mut color, button_type) in &mut interaction_query {
-        *color = match interaction {
-            Interaction::Pressed => {
-                match button_type {
-                    Buttons::Back => next_state.set(game::State::GameOver),
-                }
-                button::color::PRESSED
-            }
-            Interaction::Hovered => button::color::HOVERED,
-            Interaction::None => button::color::NORMAL,
+    if actions.just_pressed(&focus::Action::Back) {
+        next_state.set(game::State::GameOver);
+    }
+
+    for button_type in activated
+        .read()
+        .filter_map(|&focus::Activated(entity)| buttons.get(entity).ok())
+    {
+        match *button_type {
+            Buttons::Tab(period) => **board = period,
+            Buttons::Back => next_state.set(game::State::GameOver),
         }
-        .into();
     }
 }
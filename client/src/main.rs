@@ -12,6 +12,9 @@ use bevy_screen_diagnostics::{ScreenDiagnosticsPlugin, ScreenFrameDiagnosticsPlu
 mod camera;
 mod game;
 mod menu;
+mod net;
+mod rules;
+mod storage;
 mod window;
 
 #[derive(States, Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
@@ -63,7 +66,14 @@ fn main() {
     }
 
     app.init_state::<AppState>()
-        .add_plugins((window::Plugin, camera::Plugin, menu::Plugin, game::Plugin))
+        .add_plugins((
+            window::Plugin,
+            camera::Plugin,
+            net::Plugin,
+            rules::Plugin,
+            menu::Plugin,
+            game::Plugin,
+        ))
         .add_systems(Update, volume);
 
     app.run();
@@ -2,6 +2,7 @@ use crate::AppState;
 use bevy::{app, prelude::*};
 
 pub mod button;
+pub mod focus;
 mod home;
 pub mod settings;
 
@@ -17,7 +18,7 @@ pub struct Plugin;
 impl app::Plugin for Plugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<GuiData>()
-            .add_plugins((home::Plugin, settings::Plugin))
+            .add_plugins((focus::Plugin, home::Plugin, settings::Plugin))
             .add_systems(OnExit(AppState::MainMenu), cleanup)
             .add_systems(OnExit(AppState::Settings), cleanup);
     }
@@ -2,7 +2,7 @@ pub mod color {
     use bevy::prelude::*;
 
     pub const NORMAL: Color = Color::rgb(0.15, 0.15, 0.15);
-    pub const HOVERED: Color = Color::rgb(0.25, 0.25, 0.25);
+    pub const FOCUSED: Color = Color::rgb(0.25, 0.25, 0.25);
     pub const PRESSED: Color = Color::rgb(0.35, 0.75, 0.35);
 }
 
@@ -0,0 +1,224 @@
+use super::button;
+use bevy::{app, prelude::*};
+use bevy_simple_text_input::TextInputInactive;
+use leafwing_input_manager::prelude::*;
+
+pub struct Plugin;
+
+impl app::Plugin for Plugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(InputManagerPlugin::<Action>::default())
+            .init_resource::<ActionState<Action>>()
+            .insert_resource(Action::default_input_map())
+            .init_resource::<Focused>()
+            .add_event::<Activated>()
+            .add_systems(
+                Update,
+                (
+                    validate,
+                    hover,
+                    navigate,
+                    activate_text_inputs,
+                    suspend_keyboard,
+                    confirm,
+                    highlight,
+                )
+                    .chain(),
+            );
+    }
+}
+
+#[derive(Actionlike, PartialEq, Eq, Clone, Copy, Hash, Debug, Reflect)]
+pub enum Action {
+    Up,
+    Down,
+    Left,
+    Right,
+    Confirm,
+    Back,
+}
+
+impl Action {
+    fn default_input_map() -> InputMap<Self> {
+        let mut input_map = Self::typing_input_map();
+
+        input_map.insert(Self::Left, KeyCode::ArrowLeft);
+        input_map.insert(Self::Right, KeyCode::ArrowRight);
+        input_map.insert(Self::Confirm, KeyCode::Enter);
+
+        input_map
+    }
+
+    /// Leaves the Keys a Text Input uses to it.
+    /// Up and Down still move the Focus out of the Text Input.
+    fn typing_input_map() -> InputMap<Self> {
+        let mut input_map = InputMap::default();
+
+        // * NAVIGATION - START
+        input_map.insert(Self::Up, KeyCode::ArrowUp);
+        input_map.insert(Self::Up, GamepadButtonType::DPadUp);
+        input_map.insert(Self::Down, KeyCode::ArrowDown);
+        input_map.insert(Self::Down, GamepadButtonType::DPadDown);
+        input_map.insert(Self::Left, GamepadButtonType::DPadLeft);
+        input_map.insert(Self::Right, GamepadButtonType::DPadRight);
+        // * NAVIGATION - END
+
+        input_map.insert(Self::Confirm, GamepadButtonType::South);
+
+        input_map.insert(Self::Back, KeyCode::Escape);
+        input_map.insert(Self::Back, GamepadButtonType::East);
+
+        input_map
+    }
+
+    const fn direction(self) -> Option<Vec2> {
+        // UI Coordinates grow downwards
+        match self {
+            Self::Up => Some(Vec2::NEG_Y),
+            Self::Down => Some(Vec2::Y),
+            Self::Left => Some(Vec2::NEG_X),
+            Self::Right => Some(Vec2::X),
+            Self::Confirm | Self::Back => None,
+        }
+    }
+}
+
+/// Marks a Button that can be reached with the Keyboard or a Gamepad
+#[derive(Component)]
+pub struct Focusable;
+
+/// The currently focused [`Focusable`]
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct Focused(Option<Entity>);
+
+/// Sent when a [`Focusable`] is clicked or confirmed while focused
+#[derive(Event)]
+pub struct Activated(pub Entity);
+
+/// A [`Focusable`] Text Input only takes Keys while it is focused
+fn activate_text_inputs(
+    focused: Res<Focused>,
+    mut inputs: Query<(Entity, &mut TextInputInactive), With<Focusable>>,
+) {
+    for (entity, mut inactive) in &mut inputs {
+        let inactive_now = **focused != Some(entity);
+        if inactive.0 != inactive_now {
+            inactive.0 = inactive_now;
+        }
+    }
+}
+
+/// Left, Right and Enter belong to an active Text Input, otherwise Enter
+/// would also activate the focused Button. Gamepads keep navigating.
+fn suspend_keyboard(
+    (mut input_map, mut typing): (ResMut<InputMap<Action>>, Local<bool>),
+    inputs: Query<&TextInputInactive>,
+) {
+    let active = inputs.iter().any(|inactive| !inactive.0);
+    if active == *typing {
+        return;
+    }
+    *typing = active;
+    *input_map = if active {
+        Action::typing_input_map()
+    } else {
+        Action::default_input_map()
+    };
+}
+
+fn validate(mut focused: ResMut<Focused>, focusables: Query<(), With<Focusable>>) {
+    if focused.is_some_and(|entity| !focusables.contains(entity)) {
+        **focused = None;
+    }
+}
+
+fn hover(
+    mut focused: ResMut<Focused>,
+    interactions: Query<(Entity, &Interaction), (Changed<Interaction>, With<Focusable>)>,
+) {
+    for (entity, interaction) in &interactions {
+        if *interaction != Interaction::None {
+            **focused = Some(entity);
+        }
+    }
+}
+
+fn navigate(
+    (actions, mut focused): (Res<ActionState<Action>>, ResMut<Focused>),
+    focusables: Query<(Entity, &GlobalTransform), With<Focusable>>,
+) {
+    let Some(direction) = actions
+        .get_just_pressed()
+        .into_iter()
+        .find_map(Action::direction)
+    else {
+        return;
+    };
+
+    let Some((current, origin)) = focused.and_then(|entity| focusables.get(entity).ok()) else {
+        // Nothing is focused yet, so start at the top left
+        **focused = focusables
+            .iter()
+            .map(|(entity, transform)| (entity, transform.translation().truncate()))
+            .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)))
+            .map(|(entity, _)| entity);
+        return;
+    };
+
+    let origin = origin.translation().truncate();
+    let next = focusables
+        .iter()
+        .filter(|&(entity, _)| entity != current)
+        .filter_map(|(entity, transform)| {
+            let offset = transform.translation().truncate() - origin;
+            let along = offset.dot(direction);
+            if along <= 0.0 {
+                return None;
+            }
+            // Prefer Elements that are in line with the current one
+            let across = offset.perp_dot(direction).abs();
+            Some((entity, along + 2.0 * across))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    if let Some((entity, _)) = next {
+        **focused = Some(entity);
+    }
+}
+
+fn confirm(
+    (actions, focused, mut activated): (
+        Res<ActionState<Action>>,
+        Res<Focused>,
+        EventWriter<Activated>,
+    ),
+    interactions: Query<(Entity, &Interaction), (Changed<Interaction>, With<Focusable>)>,
+) {
+    for (entity, interaction) in &interactions {
+        if *interaction == Interaction::Pressed {
+            activated.send(Activated(entity));
+        }
+    }
+
+    if actions.just_pressed(&Action::Confirm) {
+        if let Some(entity) = **focused {
+            activated.send(Activated(entity));
+        }
+    }
+}
+
+fn highlight(
+    focused: Res<Focused>,
+    mut buttons: Query<(Entity, &Interaction, &mut BackgroundColor), With<Focusable>>,
+) {
+    for (entity, interaction, mut color) in &mut buttons {
+        *color = if *interaction == Interaction::Pressed {
+            button::color::PRESSED
+        } else if **focused == Some(entity) {
+            button::color::FOCUSED
+        } else {
+            button::color::NORMAL
+        }
+        .into();
+    }
+}
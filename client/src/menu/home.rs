@@ -1,9 +1,13 @@
-use super::{button, FONT_SIZE, TEXT_COLOR};
-use crate::AppState;
+use super::{button, focus, FONT_SIZE, TEXT_COLOR};
+use crate::{net, rules, AppState};
 use bevy::{
     app::{self, AppExit},
     prelude::*,
 };
+use std::time::Duration;
+
+/// How long a new #1 is announced
+const TOAST_DURATION: Duration = Duration::from_secs(5);
 
 pub struct Plugin;
 
@@ -17,7 +21,11 @@ enum Buttons {
 impl app::Plugin for Plugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(AppState::MainMenu), setup)
-            .add_systems(Update, home.run_if(in_state(AppState::MainMenu)));
+            .add_systems(
+                Update,
+                (home, announce, expire_toasts).run_if(in_state(AppState::MainMenu)),
+            )
+            .add_systems(OnExit(AppState::MainMenu), remove_toasts);
     }
 }
 
@@ -70,6 +78,7 @@ fn setup_play_button(parent: &mut ChildBuilder) {
         .spawn((
             Name::new("Play Button"),
             Buttons::Play,
+            focus::Focusable,
             ButtonBundle {
                 style: Style {
                     min_width: button::size::MIN_WIDTH,
@@ -101,6 +110,7 @@ fn setup_settings_button(parent: &mut ChildBuilder) {
         .spawn((
             Name::new("Settings Button"),
             Buttons::Settings,
+            focus::Focusable,
             ButtonBundle {
                 style: Style {
                     min_width: button::size::MIN_WIDTH,
@@ -131,6 +141,7 @@ fn setup_quit_button(parent: &mut ChildBuilder) {
         .spawn((
             Name::new("Quit Button"),
             Buttons::Quit,
+            focus::Focusable,
             ButtonBundle {
                 style: Style {
                     min_width: button::size::MIN_WIDTH,
@@ -158,26 +169,77 @@ fn setup_quit_button(parent: &mut ChildBuilder) {
 
 fn home(
     (mut next_state, mut exit): (ResMut<NextState<AppState>>, EventWriter<AppExit>),
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &Buttons),
-        (Changed<Interaction>, With<Button>),
-    >,
+    mut activated: EventReader<focus::Activated>,
+    buttons: Query<&Buttons>,
 ) {
-    for (interaction, mut color, button_type) in &mut interaction_query {
-        *color = match interaction {
-            Interaction::Pressed => {
-                match button_type {
-                    Buttons::Play => next_state.set(AppState::InGame),
-                    Buttons::Settings => next_state.set(AppState::Settings),
-                    Buttons::Quit => {
-                        exit.send_default();
-                    }
-                }
-                button::color::PRESSED
+    for button_type in activated
+        .read()
+        .filter_map(|&focus::Activated(entity)| buttons.get(entity).ok())
+    {
+        match button_type {
+            Buttons::Play => next_state.set(AppState::InGame),
+            Buttons::Settings => next_state.set(AppState::Settings),
+            Buttons::Quit => {
+                exit.send_default();
             }
-            Interaction::Hovered => button::color::HOVERED,
-            Interaction::None => button::color::NORMAL,
         }
-        .into();
+    }
+}
+
+/// Announcement of a new #1 on the Leaderboard
+#[derive(Component, Deref, DerefMut)]
+struct Toast(Timer);
+
+fn announce(
+    mut commands: Commands,
+    (mut changes, rules): (EventReader<net::live::RankChanged>, Res<rules::Rules>),
+    toasts: Query<Entity, With<Toast>>,
+) {
+    // Only the Board of the picked Rules is of Interest
+    let Some(change) = changes
+        .read()
+        .filter(|change| {
+            change.rank == 1 && change.mode == rules.mode && change.difficulty == rules.difficulty
+        })
+        .last()
+    else {
+        return;
+    };
+    // Only the latest #1 is of Interest
+    for entity in &toasts {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    commands.spawn((
+        Name::new("New #1 Toast"),
+        Toast(Timer::new(TOAST_DURATION, TimerMode::Once)),
+        TextBundle::from_section(
+            format!("New #1! {} with {}", change.name, change.score),
+            TextStyle {
+                font_size: FONT_SIZE / 2.0,
+                color: Color::YELLOW,
+                ..default()
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Vh(2.0),
+            right: Val::Vw(2.0),
+            ..default()
+        }),
+    ));
+}
+
+fn expire_toasts(mut commands: Commands, time: Res<Time>, mut toasts: Query<(Entity, &mut Toast)>) {
+    for (entity, mut toast) in &mut toasts {
+        if toast.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn remove_toasts(mut commands: Commands, toasts: Query<Entity, With<Toast>>) {
+    for entity in &toasts {
+        commands.entity(entity).despawn_recursive();
     }
 }
@@ -1,6 +1,8 @@
-use super::{button, GuiData, FONT_SIZE, TEXT_COLOR};
-use crate::{window, AppState};
+use super::{button, focus, GuiData, FONT_SIZE, TEXT_COLOR};
+use crate::{rules, window, AppState};
 use bevy::{app, prelude::*, window::PrimaryWindow};
+use leafwing_input_manager::prelude::*;
+use utils::{Difficulty, Mode};
 
 mod vsync;
 
@@ -11,7 +13,12 @@ impl app::Plugin for Plugin {
         app.add_systems(OnEnter(AppState::Settings), setup)
             .add_systems(
                 Update,
-                (menu, update_vsync).run_if(in_state(AppState::Settings)),
+                (
+                    menu,
+                    update_vsync,
+                    update_rules.run_if(resource_changed::<rules::Rules>),
+                )
+                    .run_if(in_state(AppState::Settings)),
             );
     }
 }
@@ -20,13 +27,15 @@ impl app::Plugin for Plugin {
 enum Elements {
     Presentation,
     Vsync,
+    Mode,
+    Difficulty,
     Sound,
     Back,
 }
 
 fn setup(
     mut commands: Commands,
-    mut menu_data: ResMut<GuiData>,
+    (mut menu_data, rules): (ResMut<GuiData>, Res<rules::Rules>),
     window: Query<&Window, With<PrimaryWindow>>,
 ) {
     let node = commands
@@ -49,6 +58,15 @@ fn setup(
         .with_children(setup_header)
         .with_children(setup_presentation_mode)
         .with_children(|parent| setup_vsync(parent, window))
+        .with_children(|parent| {
+            setup_rule(parent, Elements::Mode, ModeText, &mode_text(*rules));
+            setup_rule(
+                parent,
+                Elements::Difficulty,
+                DifficultyText,
+                &difficulty_text(*rules),
+            );
+        })
         .with_children(setup_back)
         .id();
     menu_data.push(node);
@@ -57,6 +75,20 @@ fn setup(
 #[derive(Component)]
 struct VsyncText;
 
+#[derive(Component)]
+struct ModeText;
+
+#[derive(Component)]
+struct DifficultyText;
+
+fn mode_text(rules: rules::Rules) -> String {
+    format!("Mode: {}", rules::mode_label(rules.mode))
+}
+
+fn difficulty_text(rules: rules::Rules) -> String {
+    format!("Difficulty: {}", rules::difficulty_label(rules.difficulty))
+}
+
 fn setup_header(parent: &mut ChildBuilder) {
     parent.spawn((
         Name::new("Settings Header"),
@@ -85,6 +117,7 @@ fn setup_presentation_mode(parent: &mut ChildBuilder) {
         .spawn((
             Name::new("Presentation Mode Setter"),
             Elements::Presentation,
+            focus::Focusable,
             ButtonBundle {
                 style: Style {
                     min_width: button::size::MIN_WIDTH,
@@ -130,6 +163,7 @@ fn setup_vsync(parent: &mut ChildBuilder, window: Query<&Window, With<PrimaryWin
                 .spawn((
                     Name::new("Vsync Toggler"),
                     Elements::Vsync,
+                    focus::Focusable,
                     ButtonBundle {
                         style: Style {
                             min_width: button::size::MIN_WIDTH,
@@ -173,11 +207,47 @@ fn setup_vsync(parent: &mut ChildBuilder, window: Query<&Window, With<PrimaryWin
         });
 }
 
+/// A Button cycling through the Values of a Rule
+fn setup_rule(parent: &mut ChildBuilder, element: Elements, marker: impl Component, text: &str) {
+    parent
+        .spawn((
+            Name::new("Rule Changer"),
+            element,
+            focus::Focusable,
+            ButtonBundle {
+                style: Style {
+                    min_width: button::size::MIN_WIDTH,
+                    min_height: button::size::MIN_HEIGHT,
+                    width: button::size::WIDTH,
+                    height: button::size::HEIGHT,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                marker,
+                TextBundle::from_section(
+                    text,
+                    TextStyle {
+                        font_size: FONT_SIZE,
+                        color: TEXT_COLOR,
+                        ..default()
+                    },
+                ),
+            ));
+        });
+}
+
 fn setup_back(parent: &mut ChildBuilder) {
     parent
         .spawn((
             Name::new("Back Button"),
             Elements::Back,
+            focus::Focusable,
             ButtonBundle {
                 style: Style {
                     min_width: button::size::MIN_WIDTH,
@@ -204,35 +274,40 @@ fn setup_back(parent: &mut ChildBuilder) {
 }
 
 fn menu(
-    (mut next_state, mut fullscreen, mut vsync_toggle): (
+    (mut next_state, mut fullscreen, mut vsync_toggle, mut rules): (
         ResMut<NextState<AppState>>,
         EventWriter<window::Fullscreen>,
         EventWriter<window::VsyncToggle>,
+        ResMut<rules::Rules>,
     ),
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &Elements),
-        (Changed<Interaction>, With<Button>),
-    >,
+    (actions, mut activated): (
+        Res<ActionState<focus::Action>>,
+        EventReader<focus::Activated>,
+    ),
+    elements: Query<&Elements>,
 ) {
-    for (interaction, mut color, element_type) in &mut interaction_query {
-        *color = match *interaction {
-            Interaction::Pressed => {
-                match element_type {
-                    Elements::Presentation => {
-                        fullscreen.send(window::Fullscreen);
-                    }
-                    Elements::Vsync => {
-                        vsync_toggle.send(window::VsyncToggle);
-                    }
-                    Elements::Sound => todo!("Sound Capabilites have not been implemented yet."),
-                    Elements::Back => next_state.set(AppState::MainMenu),
-                }
-                button::color::PRESSED
+    if actions.just_pressed(&focus::Action::Back) {
+        next_state.set(AppState::MainMenu);
+    }
+
+    for element_type in activated
+        .read()
+        .filter_map(|&focus::Activated(entity)| elements.get(entity).ok())
+    {
+        match element_type {
+            Elements::Presentation => {
+                fullscreen.send(window::Fullscreen);
             }
-            Interaction::Hovered => button::color::HOVERED,
-            Interaction::None => button::color::NORMAL,
+            Elements::Vsync => {
+                vsync_toggle.send(window::VsyncToggle);
+            }
+            Elements::Mode => rules.mode = rules::next(&Mode::ALL, rules.mode),
+            Elements::Difficulty => {
+                rules.difficulty = rules::next(&Difficulty::ALL, rules.difficulty);
+            }
+            Elements::Sound => todo!("Sound Capabilites have not been implemented yet."),
+            Elements::Back => next_state.set(AppState::MainMenu),
         }
-        .into();
     }
 }
 
@@ -259,3 +334,14 @@ fn update_vsync(
         },
     }
 }
+
+fn update_rules(
+    rules: Res<rules::Rules>,
+    (mut mode_texts, mut difficulty_texts): (
+        Query<&mut Text, (With<ModeText>, Without<DifficultyText>)>,
+        Query<&mut Text, With<DifficultyText>>,
+    ),
+) {
+    mode_texts.single_mut().sections[0].value = mode_text(*rules);
+    difficulty_texts.single_mut().sections[0].value = difficulty_text(*rules);
+}
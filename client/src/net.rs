@@ -0,0 +1,288 @@
+use crate::{rules::Rules, storage};
+use bevy::{app, prelude::*};
+use reqwest::{
+    blocking::{Client, Response},
+    header::RETRY_AFTER,
+    StatusCode, Url,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::{
+    env, fmt,
+    sync::{
+        mpsc::{self, Receiver, TryRecvError},
+        Mutex, PoisonError,
+    },
+    thread,
+    time::Duration,
+};
+use utils::{ErrorBody, Page, Period, PlayerRank, Recorded, Run};
+
+mod identity;
+pub mod live;
+pub mod queue;
+
+const DEFAULT_SERVER: &str = "https://bvspcinv-backend.shuttleapp.rs/";
+/// Path of the API Version this Client speaks, relative to the Server
+const API: &str = "api/v1/";
+const SERVER_FLAG: &str = "--server";
+const SERVER_ENV: &str = "BVSPCINV_SERVER";
+const SETTINGS_FILE: &str = "settings.json";
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_ATTEMPTS: u32 = 3;
+const BACKOFF: Duration = Duration::from_millis(250);
+/// Longest `Retry-After` worth waiting for, the Request fails instead
+const MAX_WAIT: Duration = Duration::from_secs(10);
+
+pub struct Plugin;
+
+impl app::Plugin for Plugin {
+    fn build(&self, app: &mut App) {
+        let server = Server::configured();
+        info!("Using Leaderboard Server {}", *server);
+        app.insert_resource(server)
+            .add_plugins((live::Plugin, queue::Plugin));
+    }
+}
+
+#[derive(Deserialize)]
+struct Settings {
+    server: Option<String>,
+}
+
+/// Base URL of the Leaderboard Backend
+#[derive(Resource, Deref)]
+pub struct Server(Url);
+
+impl Server {
+    /// Picks the Server from the `--server` Flag, the `BVSPCINV_SERVER`
+    /// Environment Variable or the Settings File, in that Order
+    fn configured() -> Self {
+        let from_args = || {
+            let mut args = env::args().skip(1);
+            while let Some(arg) = args.next() {
+                if arg == SERVER_FLAG {
+                    return args.next();
+                }
+                if let Some(url) = arg
+                    .strip_prefix(SERVER_FLAG)
+                    .and_then(|a| a.strip_prefix('='))
+                {
+                    return Some(url.to_owned());
+                }
+            }
+            None
+        };
+        let from_settings = || storage::load::<Settings>(SETTINGS_FILE).and_then(|s| s.server);
+
+        from_args()
+            .or_else(|| env::var(SERVER_ENV).ok())
+            .or_else(from_settings)
+            .and_then(|url| Self::parse(&url))
+            .unwrap_or_else(|| Self::parse(DEFAULT_SERVER).expect("The default Server is valid"))
+    }
+
+    fn parse(url: &str) -> Option<Self> {
+        // Without a trailing Slash `join` would replace the last Path Segment
+        let url = if url.ends_with('/') {
+            Url::parse(url)
+        } else {
+            Url::parse(&format!("{url}/"))
+        };
+        url.map(Self)
+            .map_err(|e| warn!("Ignoring invalid Server URL: {e}"))
+            .ok()
+    }
+
+    /// URL of an Endpoint of the API, e.g. `boards/daily`
+    pub fn endpoint(&self, path: &str) -> Url {
+        self.join(&format!("{API}{path}"))
+            .expect("Endpoint Paths are valid")
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Timeout,
+    Connection,
+    Status(StatusCode),
+    /// The Server doesn't know the Player Token
+    Unauthorized,
+    /// Too many Requests, the Server asked to wait this long
+    RateLimited(Duration),
+    /// The Server refused the Request and explained why
+    Rejected(String),
+    Decode,
+}
+
+impl Error {
+    /// Whether sending the same Request again might succeed
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Timeout | Self::Connection | Self::RateLimited(_) => true,
+            Self::Status(status) => status.is_server_error(),
+            Self::Unauthorized | Self::Rejected(_) | Self::Decode => false,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(value: reqwest::Error) -> Self {
+        if value.is_timeout() {
+            Self::Timeout
+        } else if let Some(status) = value.status() {
+            Self::Status(status)
+        } else if value.is_decode() {
+            Self::Decode
+        } else {
+            Self::Connection
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Timeout => write!(f, "The Server took too long to respond"),
+            Self::Connection => write!(f, "Couldn't connect to the Server"),
+            Self::Status(status) => write!(f, "The Server responded with {status}"),
+            Self::Unauthorized => write!(f, "The Server didn't accept the Player Token"),
+            Self::RateLimited(wait) => write!(
+                f,
+                "Too many Requests, try again in {} Seconds",
+                wait.as_secs().max(1)
+            ),
+            Self::Rejected(message) => write!(f, "{message}"),
+            Self::Decode => write!(f, "The Server sent malformed Data"),
+        }
+    }
+}
+
+/// A HTTP Request running on its own Thread, so a slow Server never
+/// holds up the Task Pools the Engine loads Assets with.
+/// Dropping it discards the Answer.
+#[derive(Component)]
+pub struct Request<T>(Mutex<Receiver<Result<T, Error>>>);
+
+impl<T: Send + 'static> Request<T> {
+    fn spawn(send: impl Fn(&Client) -> Result<T, Error> + Send + 'static) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let worker = sender.clone();
+        let thread = thread::Builder::new()
+            .name("Leaderboard Request".into())
+            .spawn(move || {
+                let result = Client::builder()
+                    .timeout(TIMEOUT)
+                    .build()
+                    .map_err(Error::from)
+                    .and_then(|client| retry(|| send(&client)));
+                // Nobody is interested anymore if the Request was dropped
+                let _ = worker.send(result);
+            });
+        if let Err(e) = thread {
+            warn!("Couldn't start a Request: {e}");
+            let _ = sender.send(Err(Error::Connection));
+        }
+        Self(Mutex::new(receiver))
+    }
+
+    /// Returns the Result once the Request has finished
+    pub fn poll(&mut self) -> Option<Result<T, Error>> {
+        let receiver = self.0.get_mut().unwrap_or_else(PoisonError::into_inner);
+        match receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            // The Thread died without answering
+            Err(TryRecvError::Disconnected) => Some(Err(Error::Connection)),
+        }
+    }
+}
+
+/// Retries transient Errors with an exponential Backoff,
+/// or after as long as the Server asks for
+fn retry<T>(send: impl Fn() -> Result<T, Error>) -> Result<T, Error> {
+    let mut attempt = 1;
+    loop {
+        match send() {
+            Err(e) if e.is_transient() && attempt < MAX_ATTEMPTS => {
+                let wait = match e {
+                    Error::RateLimited(wait) if wait > MAX_WAIT => return Err(e),
+                    Error::RateLimited(wait) => wait,
+                    _ => BACKOFF * 2u32.pow(attempt - 1),
+                };
+                warn!("Request failed (attempt {attempt}/{MAX_ATTEMPTS}): {e}");
+                thread::sleep(wait);
+                attempt += 1;
+            }
+            res => return res,
+        }
+    }
+}
+
+fn get<T: DeserializeOwned + Send + 'static>(url: Url) -> Request<T> {
+    Request::spawn(move |client| Ok(check(client.get(url.clone()).send()?)?.json()?))
+}
+
+/// Selects the Board of the Mode and Difficulty
+fn select(url: &mut Url, rules: Rules) {
+    url.query_pairs_mut()
+        .append_pair("mode", rules.mode.as_str())
+        .append_pair("difficulty", rules.difficulty.as_str());
+}
+
+/// The best `limit` Entries of the Board of the `period` played by the Rules
+pub fn board(server: &Server, period: Period, rules: Rules, limit: usize) -> Request<Page> {
+    let mut url = server.endpoint(&format!("boards/{}", period.as_str()));
+    select(&mut url, rules);
+    url.query_pairs_mut()
+        .append_pair("limit", &limit.to_string());
+    get(url)
+}
+
+/// Where the best Entry of the Player on the Board of the Rules places
+pub fn player(server: &Server, name: &str, rules: Rules) -> Request<PlayerRank> {
+    let mut url = server.endpoint("players/");
+    url.path_segments_mut()
+        .expect("Server URLs are valid Base URLs")
+        .pop_if_empty()
+        .push(name);
+    select(&mut url, rules);
+    get(url)
+}
+
+/// Submits a finished Run to the Leaderboard.
+/// Registers the Name on the first Submission and renames the Player when it changes.
+pub fn submit(server: &Server, run: Run) -> Request<Recorded> {
+    let (api, url) = (server.endpoint(""), server.endpoint("runs"));
+    Request::spawn(move |client| {
+        identity::authenticated(client, &api, &run.name, |token| {
+            send_run(client, url.clone(), token, &run)
+        })
+    })
+}
+
+fn send_run(client: &Client, url: Url, token: &str, run: &Run) -> Result<Recorded, Error> {
+    Ok(check(client.post(url).bearer_auth(token).json(run).send()?)?.json()?)
+}
+
+/// Turns Error Responses into [`Error`]s, keeping the Message of the Backend
+fn check(response: Response) -> Result<Response, Error> {
+    let status = response.status();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        let wait = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok()?.parse().ok())
+            .map_or(BACKOFF, Duration::from_secs);
+        return Err(Error::RateLimited(wait));
+    }
+    if status == StatusCode::UNAUTHORIZED {
+        return Err(Error::Unauthorized);
+    }
+    if status.is_client_error() {
+        return Err(response
+            .json::<ErrorBody>()
+            .map_or(Error::Status(status), |body| Error::Rejected(body.message)));
+    }
+    Ok(response.error_for_status()?)
+}
@@ -0,0 +1,96 @@
+use super::{check, Error};
+use crate::storage;
+use reqwest::{blocking::Client, Url};
+use std::collections::BTreeMap;
+use utils::{Credentials, PlayerName};
+
+/// Credentials of this Installation per Server, keyed by its API URL
+const FILE: &str = "identities.json";
+
+type Identities = BTreeMap<String, Credentials>;
+
+fn identities() -> Identities {
+    storage::load(FILE).unwrap_or_default()
+}
+
+/// The Credentials of this Installation on the Server, if it registered already
+pub fn load(api: &Url) -> Option<Credentials> {
+    identities().remove(api.as_str())
+}
+
+fn save(api: &Url, credentials: Option<&Credentials>) {
+    let mut identities = identities();
+    match credentials {
+        Some(credentials) => identities.insert(api.to_string(), credentials.clone()),
+        None => identities.remove(api.as_str()),
+    };
+    storage::save(FILE, &identities);
+}
+
+/// Sends a Request as the Owner of `name`, registering or renaming as needed.
+/// If the Server doesn't know the Token, e.g. after it moved to another Database,
+/// the stored Credentials are dropped and the Name is claimed again once.
+pub fn authenticated<T>(
+    client: &Client,
+    api: &Url,
+    name: &str,
+    send: impl Fn(&str) -> Result<T, Error>,
+) -> Result<T, Error> {
+    let credentials = match ensure(client, api, name) {
+        Err(Error::Unauthorized) => return send(&reclaim(client, api, name)?.token),
+        credentials => credentials?,
+    };
+    match send(&credentials.token) {
+        Err(Error::Unauthorized) => send(&reclaim(client, api, name)?.token),
+        res => res,
+    }
+}
+
+/// Makes sure the Player owns `name`, registering or renaming as needed.
+/// New Credentials are saved right away, so they survive a failing Submission.
+fn ensure(client: &Client, api: &Url, name: &str) -> Result<Credentials, Error> {
+    match load(api) {
+        Some(credentials) if credentials.name == name => Ok(credentials),
+        Some(credentials) => rename(client, api, credentials, name),
+        None => register(client, api, name),
+    }
+}
+
+fn reclaim(client: &Client, api: &Url, name: &str) -> Result<Credentials, Error> {
+    save(api, None);
+    register(client, api, name)
+}
+
+fn register(client: &Client, api: &Url, name: &str) -> Result<Credentials, Error> {
+    let url = api.join("players").expect("Endpoint Paths are valid");
+    let body = PlayerName { name: name.into() };
+    let credentials = check(client.post(url).json(&body).send()?)?.json::<Credentials>()?;
+    save(api, Some(&credentials));
+    Ok(credentials)
+}
+
+fn rename(
+    client: &Client,
+    api: &Url,
+    credentials: Credentials,
+    name: &str,
+) -> Result<Credentials, Error> {
+    let url = api
+        .join("players/rename")
+        .expect("Endpoint Paths are valid");
+    let body = PlayerName { name: name.into() };
+    check(
+        client
+            .post(url)
+            .bearer_auth(&credentials.token)
+            .json(&body)
+            .send()?,
+    )?;
+
+    let credentials = Credentials {
+        name: name.into(),
+        ..credentials
+    };
+    save(api, Some(&credentials));
+    Ok(credentials)
+}
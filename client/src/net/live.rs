@@ -0,0 +1,92 @@
+use super::{check, Error, Server};
+use bevy::{app, prelude::*};
+use reqwest::{blocking::Client, Url};
+use std::{
+    io::{BufRead, BufReader},
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+use utils::RankedEntry;
+
+/// Wait before reconnecting once the Stream broke off
+const RECONNECT: Duration = Duration::from_secs(10);
+
+pub struct Plugin;
+
+impl app::Plugin for Plugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<RankChanged>()
+            .add_systems(Startup, connect)
+            .add_systems(PreUpdate, receive.run_if(resource_exists::<Changes>));
+    }
+}
+
+/// A Player set a new personal Best and now has this Rank
+#[derive(Event, Deref)]
+pub struct RankChanged(pub RankedEntry);
+
+/// Rank Changes pushed by the Server, received on their own Thread
+#[derive(Resource)]
+struct Changes(Mutex<Receiver<RankedEntry>>);
+
+fn connect(mut commands: Commands, server: Res<Server>) {
+    let url = server.endpoint("events");
+    let (sender, receiver) = mpsc::channel();
+
+    let listener = thread::Builder::new()
+        .name("Leaderboard Events".into())
+        .spawn(move || listen(&url, &sender));
+    match listener {
+        Ok(_) => commands.insert_resource(Changes(Mutex::new(receiver))),
+        Err(e) => warn!("Couldn't listen for Leaderboard Changes: {e}"),
+    }
+}
+
+/// Keeps the Stream open for as long as the App runs
+fn listen(url: &Url, sender: &Sender<RankedEntry>) {
+    // The Stream stays open, so it must not time out
+    let Ok(client) = Client::builder().timeout(None).build() else {
+        return;
+    };
+    while let Err(e) = stream(&client, url, sender) {
+        debug!("Leaderboard Events interrupted: {e}");
+        thread::sleep(RECONNECT);
+    }
+}
+
+/// Forwards every Event until the Stream breaks off.
+/// Returns once nobody receives the Changes anymore.
+fn stream(client: &Client, url: &Url, sender: &Sender<RankedEntry>) -> Result<(), Error> {
+    let response = check(client.get(url.clone()).send()?)?;
+
+    let mut data = String::new();
+    for line in BufReader::new(response).lines() {
+        let line = line.map_err(|_| Error::Connection)?;
+        if let Some(value) = line.strip_prefix("data:") {
+            data.push_str(value.trim_start());
+        } else if line.is_empty() && !data.is_empty() {
+            // An empty Line ends the Event
+            match serde_json::from_str(&data) {
+                Ok(entry) => {
+                    if sender.send(entry).is_err() {
+                        return Ok(());
+                    }
+                }
+                Err(e) => warn!("Ignoring malformed Leaderboard Event: {e}"),
+            }
+            data.clear();
+        }
+    }
+    Err(Error::Connection)
+}
+
+fn receive(changes: Res<Changes>, mut changed: EventWriter<RankChanged>) {
+    let Ok(receiver) = changes.0.lock() else {
+        return;
+    };
+    changed.send_batch(receiver.try_iter().map(RankChanged));
+}
@@ -0,0 +1,119 @@
+use super::{identity, send_run, Request, Server};
+use crate::storage;
+use bevy::{app, prelude::*};
+use utils::Run;
+
+const FILE: &str = "pending.json";
+
+pub struct Plugin;
+
+impl app::Plugin for Plugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Pending::load())
+            .add_event::<Flush>()
+            .add_systems(Startup, flush_on_launch)
+            .add_systems(Update, (flush, flushed).chain());
+    }
+}
+
+/// Submissions that couldn't reach the Server yet.
+/// Kept on Disk so they survive a Restart.
+#[derive(Resource, Default, Deref)]
+pub struct Pending(Vec<Run>);
+
+impl Pending {
+    fn load() -> Self {
+        Self(storage::load(FILE).unwrap_or_default())
+    }
+
+    pub fn push(&mut self, run: Run) {
+        if !self.0.contains(&run) {
+            self.0.push(run);
+            storage::save(FILE, &self.0);
+        }
+    }
+
+    pub fn remove(&mut self, sent: &[Run]) {
+        let len = self.0.len();
+        self.0.retain(|run| !sent.contains(run));
+        if self.0.len() != len {
+            storage::save(FILE, &self.0);
+        }
+    }
+}
+
+/// Retries all pending Submissions
+#[derive(Event, Default)]
+pub struct Flush;
+
+#[derive(Component)]
+struct Flushing;
+
+/// The Submissions that made it to the Server or were rejected for good
+struct Done(Vec<Run>);
+
+fn flush_on_launch(mut flush: EventWriter<Flush>) {
+    flush.send_default();
+}
+
+fn flush(
+    mut commands: Commands,
+    (mut events, pending, server): (EventReader<Flush>, Res<Pending>, Res<Server>),
+    flushing: Query<(), With<Flushing>>,
+) {
+    if events.read().count() == 0 || pending.is_empty() || !flushing.is_empty() {
+        return;
+    }
+
+    info!("Retrying {} pending Submission(s)", pending.len());
+    let (api, url, runs) = (
+        server.endpoint(""),
+        server.endpoint("runs"),
+        pending.0.clone(),
+    );
+    commands.spawn((
+        Name::new("Pending Submissions"),
+        Flushing,
+        Request::spawn(move |client| {
+            // Runs from before the first Registration wait for the next Submission
+            let Some(credentials) = identity::load(&api) else {
+                return Ok(Done(Vec::new()));
+            };
+            // Only Runs that might get through later stay pending until the next Flush
+            let done = runs
+                .iter()
+                .filter(|run| {
+                    match identity::authenticated(client, &api, &credentials.name, |token| {
+                        send_run(client, url.clone(), token, run)
+                    }) {
+                        Ok(_) => true,
+                        Err(e) if e.is_transient() => false,
+                        Err(e) => {
+                            warn!("Dropping pending Run of {} ({}): {e}", run.name, run.score);
+                            true
+                        }
+                    }
+                })
+                .cloned()
+                .collect();
+            Ok(Done(done))
+        }),
+    ));
+}
+
+fn flushed(
+    mut commands: Commands,
+    mut pending: ResMut<Pending>,
+    mut requests: Query<(Entity, &mut Request<Done>), With<Flushing>>,
+) {
+    for (entity, mut request) in &mut requests {
+        let Some(result) = request.poll() else {
+            continue;
+        };
+        commands.entity(entity).despawn();
+
+        if let Ok(Done(done)) = result {
+            pending.remove(&done);
+        }
+    }
+}
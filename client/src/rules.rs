@@ -0,0 +1,68 @@
+use crate::storage;
+use bevy::{app, prelude::*};
+use serde::{Deserialize, Serialize};
+use utils::{Difficulty, Mode};
+
+const FILE: &str = "rules.json";
+
+pub struct Plugin;
+
+impl app::Plugin for Plugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Rules::load()).add_systems(
+            Update,
+            save.run_if(resource_changed::<Rules>.and_then(not(resource_added::<Rules>))),
+        );
+    }
+}
+
+/// Mode and Difficulty the next Game is played with.
+/// Each Combination has its own Leaderboards.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rules {
+    #[serde(default)]
+    pub mode: Mode,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+impl Rules {
+    /// The Rules last picked, or the Defaults
+    fn load() -> Self {
+        storage::load(FILE).unwrap_or_default()
+    }
+
+    /// Name of the Board, e.g. `Time Attack, Hard`
+    pub fn label(self) -> String {
+        format!(
+            "{}, {}",
+            mode_label(self.mode),
+            difficulty_label(self.difficulty)
+        )
+    }
+}
+
+pub const fn mode_label(mode: Mode) -> &'static str {
+    match mode {
+        Mode::Classic => "Classic",
+        Mode::TimeAttack => "Time Attack",
+    }
+}
+
+pub const fn difficulty_label(difficulty: Difficulty) -> &'static str {
+    match difficulty {
+        Difficulty::Easy => "Easy",
+        Difficulty::Normal => "Normal",
+        Difficulty::Hard => "Hard",
+    }
+}
+
+/// The Element after the current one, starting over after the last
+pub fn next<T: Copy + PartialEq>(all: &[T], current: T) -> T {
+    let index = all.iter().position(|&t| t == current).unwrap_or(0);
+    all[(index + 1) % all.len()]
+}
+
+fn save(rules: Res<Rules>) {
+    storage::save(FILE, &*rules);
+}
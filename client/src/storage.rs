@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fs, path::PathBuf};
+
+const APP_DIR: &str = "bvspcinv";
+
+fn path(file: &str) -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join(APP_DIR).join(file))
+}
+
+/// Loads a JSON File from the Game's Data Directory.
+/// Missing or malformed Files are treated as absent.
+pub fn load<T: DeserializeOwned>(file: &str) -> Option<T> {
+    let path = path(file)?;
+    let content = fs::read_to_string(&path).ok()?;
+    serde_json::from_str(&content)
+        .map_err(|e| warn!("Ignoring malformed {}: {e}", path.display()))
+        .ok()
+}
+
+/// Saves a JSON File to the Game's Data Directory
+pub fn save<T: Serialize>(file: &str, value: &T) {
+    let Some(path) = path(file) else {
+        warn!("No Data Directory available to save {file}");
+        return;
+    };
+
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, serde_json::to_string_pretty(value)?));
+    if let Err(e) = result {
+        warn!("Couldn't save {}: {e}", path.display());
+    }
+}
@@ -0,0 +1,105 @@
+//! Moderation and Backups, only available with the Admin Token
+
+use crate::{Difficulty, Mode};
+use serde::{Deserialize, Serialize};
+
+/// An Entry as Admins see it, including hidden ones
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ModeratedEntry {
+    pub name: String,
+    pub score: i64,
+    /// Hidden Entries don't show up on any Leaderboard
+    pub hidden: bool,
+    pub mode: Mode,
+    pub difficulty: Difficulty,
+}
+
+/// Body of `POST /admin/bans/<name>`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Ban {
+    /// Why the Player was banned, kept in the Audit Log
+    pub reason: String,
+}
+
+/// A Moderation Action taken by an Admin
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AuditRecord {
+    /// What was done, e.g. `hide` or `ban`
+    pub action: String,
+    /// Name of the Entry or Player it was done to, `*` for all of them
+    pub target: String,
+    pub detail: Option<String>,
+    /// Unix Timestamp of the Action
+    pub created_at: i64,
+}
+
+/// A stored Run together with its Owner, as it appears in a Backup.
+/// The same Shape is used for JSON and CSV.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ExportedRun {
+    pub name: String,
+    pub score: i64,
+    pub level: i32,
+    pub duration_secs: i32,
+    pub client_version: String,
+    /// Whether the Entry of the Name is hidden
+    pub hidden: bool,
+    /// Unix Timestamp of the Submission
+    pub submitted_at: i64,
+    /// Backups from before Modes existed only have Classic Runs on Normal
+    #[serde(default)]
+    pub mode: Mode,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+/// What an Import does with Names that already have an Entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum OnConflict {
+    /// Keeps the stored Entry and ignores the Runs of the Backup
+    #[default]
+    Skip,
+    /// Replaces the stored Runs with the ones of the Backup
+    Overwrite,
+    /// Rejects the whole Import
+    Fail,
+}
+
+impl OnConflict {
+    pub const ALL: [Self; 3] = [Self::Skip, Self::Overwrite, Self::Fail];
+
+    /// Query Value of the Mode, e.g. `on_conflict=overwrite`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Skip => "skip",
+            Self::Overwrite => "overwrite",
+            Self::Fail => "fail",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(mode: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.as_str() == mode)
+    }
+}
+
+/// Outcome of an Import
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Imported {
+    /// Names whose Runs were imported
+    pub entries: i64,
+    pub runs: i64,
+    /// Names that already had an Entry and were left alone
+    pub skipped: i64,
+}
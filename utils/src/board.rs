@@ -0,0 +1,163 @@
+//! Leaderboards and where Players place on them
+
+use crate::unknown_version;
+use serde::{Deserialize, Serialize};
+
+/// The best Run of a Player on the Board of its Mode and Difficulty.
+/// Older Backends only send `name` and `score`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Entry {
+    pub name: String,
+    pub score: i64,
+    /// Level the best Run ended in
+    #[serde(default)]
+    pub level: i32,
+    #[serde(default = "unknown_version")]
+    pub client_version: String,
+    #[serde(default)]
+    pub mode: Mode,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RankedEntry {
+    /// 1-based Position on the Leaderboard. Equal Scores share a Rank.
+    pub rank: i64,
+    pub name: String,
+    pub score: i64,
+    #[serde(default)]
+    pub level: i32,
+    #[serde(default = "unknown_version")]
+    pub client_version: String,
+    #[serde(default)]
+    pub mode: Mode,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+/// Rules a Game is played by. Every Mode has its own Leaderboards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "TEXT", rename_all = "kebab-case"))]
+pub enum Mode {
+    /// Level after Level until the Player is hit
+    #[default]
+    Classic,
+    /// Like Classic, but the Game ends after [`Mode::TIME_LIMIT_SECS`]
+    TimeAttack,
+}
+
+impl Mode {
+    pub const ALL: [Self; 2] = [Self::Classic, Self::TimeAttack];
+
+    /// How long a Time Attack lasts
+    pub const TIME_LIMIT_SECS: u64 = 3 * 60;
+
+    /// Query Value of the Mode, e.g. `mode=time-attack`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Classic => "classic",
+            Self::TimeAttack => "time-attack",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(mode: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.as_str() == mode)
+    }
+}
+
+/// How aggressive the Invaders are. Every Difficulty has its own Leaderboards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "TEXT", rename_all = "kebab-case"))]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Self; 3] = [Self::Easy, Self::Normal, Self::Hard];
+
+    /// Query Value of the Difficulty, e.g. `difficulty=hard`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Easy => "easy",
+            Self::Normal => "normal",
+            Self::Hard => "hard",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(difficulty: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.as_str() == difficulty)
+    }
+}
+
+/// Time Span a Leaderboard covers.
+/// Boards reset at Midnight UTC, Weeks start on Monday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum Period {
+    Daily,
+    Weekly,
+    Monthly,
+    #[default]
+    AllTime,
+}
+
+impl Period {
+    pub const ALL: [Self; 4] = [Self::Daily, Self::Weekly, Self::Monthly, Self::AllTime];
+
+    /// Path Segment of the Board, e.g. `boards/weekly`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Monthly => "monthly",
+            Self::AllTime => "all-time",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(period: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.as_str() == period)
+    }
+}
+
+/// One Page of the ranked Leaderboard
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Page {
+    /// Number of Entries across all Pages
+    pub total: i64,
+    pub entries: Vec<RankedEntry>,
+}
+
+/// Where a Player's best Entry places on the Leaderboard
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PlayerRank {
+    pub entry: RankedEntry,
+    /// Number of Players on the Leaderboard
+    pub total: i64,
+    /// Share of Players ranked below this one, from 0 to 100
+    pub percentile: f64,
+    /// The Entries right above and below, best first
+    pub neighbours: Vec<RankedEntry>,
+}
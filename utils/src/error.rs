@@ -0,0 +1,42 @@
+//! What the Backend answers when a Request fails
+
+use serde::{Deserialize, Serialize};
+
+/// Body of every Error Response of the Backend
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ErrorBody {
+    /// Machine readable Kind of the Error
+    pub error: ErrorKind,
+    /// Human readable Description
+    pub message: String,
+}
+
+/// Why a Request failed, e.g. `validation`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// The Request is malformed
+    BadRequest,
+    /// The Token is missing or wrong
+    Unauthorized,
+    /// The submitted Data breaks a Rule
+    Validation,
+    /// The Name or Resource already belongs to someone else
+    Conflict,
+    NotFound,
+    /// The IP Address or Player is on the Ban List
+    Banned,
+    /// Too many Requests, the Response says when to try again
+    RateLimited,
+    /// The Request Body exceeds the configured Limit
+    TooLarge,
+    /// A Dependency like the Database can't be reached
+    Unavailable,
+    /// Something broke on the Backend's Side
+    Internal,
+    /// A Kind this Version doesn't know yet
+    #[serde(other)]
+    Unknown,
+}
@@ -1,9 +1,26 @@
-use serde::{Deserialize, Serialize};
-#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
-pub struct Entry {
-    pub name: String,
-    pub score: i64,
+//! The Protocol spoken between the Client and the Backend: the Bodies of every
+//! Request and Response, and the Rules both Sides check Names and Runs against.
+//!
+//! Database Support is behind the `sqlx` Feature, so the Client doesn't pull it in.
+
+pub mod points;
+pub mod validation;
+
+mod admin;
+mod board;
+mod error;
+mod player;
+mod run;
+
+pub use admin::{AuditRecord, Ban, ExportedRun, Imported, ModeratedEntry, OnConflict};
+pub use board::{Difficulty, Entry, Mode, Page, Period, PlayerRank, RankedEntry};
+pub use error::{ErrorBody, ErrorKind};
+pub use player::{Credentials, PlayerName};
+pub use run::{Recorded, Run, RunRecord};
+
+fn unknown_version() -> String {
+    "unknown".into()
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests;
@@ -0,0 +1,19 @@
+//! Claiming and renaming Names
+
+use serde::{Deserialize, Serialize};
+
+/// Body of `POST /players` and `POST /players/rename`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct PlayerName {
+    pub name: String,
+}
+
+/// Issued once on Registration. The Token has to be kept secret,
+/// it is sent as `Authorization: Bearer <token>`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Credentials {
+    pub name: String,
+    pub token: String,
+}
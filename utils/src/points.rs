@@ -0,0 +1,25 @@
+//! Points the Game awards, shared so the Backend can check submitted Scores
+
+pub const SQUID: usize = 30;
+pub const CRAB: usize = 20;
+pub const OCTOPUS: usize = 10;
+pub const UFO: [usize; 5] = [50, 100, 150, 200, 300];
+
+/// Every reachable Score is a Multiple of this
+pub const STEP: usize = {
+    let mut step = gcd(gcd(SQUID, CRAB), OCTOPUS);
+    let mut i = 0;
+    while i < UFO.len() {
+        step = gcd(step, UFO[i]);
+        i += 1;
+    }
+    step
+};
+
+const fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
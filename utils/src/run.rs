@@ -0,0 +1,53 @@
+//! Submitting finished Games
+
+use crate::{unknown_version, Difficulty, Mode};
+use serde::{Deserialize, Serialize};
+
+/// A finished Game as submitted by the Client.
+/// Older Clients only send `name` and `score`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Run {
+    pub name: String,
+    pub score: i64,
+    /// Level the Game ended in
+    #[serde(default)]
+    pub level: i32,
+    /// Time spent playing, without Menus
+    #[serde(default)]
+    pub duration_secs: i32,
+    #[serde(default = "unknown_version")]
+    pub client_version: String,
+    /// Board the Run counts for
+    #[serde(default)]
+    pub mode: Mode,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+/// Response to a submitted [`Run`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Recorded {
+    /// Whether the Run beat the previous best Score of the Player
+    pub personal_best: bool,
+    /// Best Score of the Player including the Run
+    pub best: i64,
+}
+
+/// A stored [`Run`] of a Player
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::FromRow))]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RunRecord {
+    pub score: i64,
+    pub level: i32,
+    pub duration_secs: i32,
+    pub client_version: String,
+    /// Unix Timestamp of the Submission
+    pub submitted_at: i64,
+    #[serde(default)]
+    pub mode: Mode,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
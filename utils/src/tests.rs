@@ -0,0 +1,207 @@
+use crate::{
+    validation::{self, NameError, RunError, ScoreError},
+    AuditRecord, Credentials, Difficulty, Entry, ErrorBody, ErrorKind, ExportedRun, Imported, Mode,
+    OnConflict, Page, Period, PlayerRank, RankedEntry, Recorded, Run,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::Debug;
+
+/// Serializes the Value and reads it back
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+    let json = serde_json::to_string(value).unwrap();
+    assert_eq!(&serde_json::from_str::<T>(&json).unwrap(), value, "{json}");
+}
+
+fn ranked(rank: i64, name: &str, score: i64) -> RankedEntry {
+    RankedEntry {
+        rank,
+        name: name.into(),
+        score,
+        level: 2,
+        client_version: "0.1.0".into(),
+        mode: Mode::TimeAttack,
+        difficulty: Difficulty::Hard,
+    }
+}
+
+fn run(score: i64) -> Run {
+    Run {
+        name: "alice".into(),
+        score,
+        level: 3,
+        duration_secs: 120,
+        client_version: "0.1.0".into(),
+        mode: Mode::Classic,
+        difficulty: Difficulty::Normal,
+    }
+}
+
+#[test]
+fn bodies_survive_a_round_trip() {
+    round_trip(&run(120));
+    round_trip(&Recorded {
+        personal_best: true,
+        best: 120,
+    });
+    round_trip(&Credentials {
+        name: "alice".into(),
+        token: "secret".into(),
+    });
+    round_trip(&Page {
+        total: 2,
+        entries: vec![ranked(1, "alice", 120), ranked(2, "bob", 90)],
+    });
+    round_trip(&PlayerRank {
+        entry: ranked(2, "bob", 90),
+        total: 2,
+        percentile: 50.0,
+        neighbours: vec![ranked(1, "alice", 120)],
+    });
+    round_trip(&AuditRecord {
+        action: "import".into(),
+        target: "*".into(),
+        detail: None,
+        created_at: 1_700_000_000,
+    });
+    round_trip(&ExportedRun {
+        name: "alice".into(),
+        score: 120,
+        level: 3,
+        duration_secs: 120,
+        client_version: "0.1.0".into(),
+        hidden: false,
+        submitted_at: 1_700_000_000,
+        mode: Mode::TimeAttack,
+        difficulty: Difficulty::Easy,
+    });
+    round_trip(&Imported {
+        entries: 1,
+        runs: 2,
+        skipped: 0,
+    });
+    round_trip(&ErrorBody {
+        error: ErrorKind::RateLimited,
+        message: "Too many Requests".into(),
+    });
+}
+
+#[test]
+fn enums_serialize_like_their_query_values() {
+    for mode in Mode::ALL {
+        assert_eq!(serde_json::to_value(mode).unwrap(), mode.as_str());
+        assert_eq!(Mode::parse(mode.as_str()), Some(mode));
+    }
+    for difficulty in Difficulty::ALL {
+        assert_eq!(
+            serde_json::to_value(difficulty).unwrap(),
+            difficulty.as_str()
+        );
+        assert_eq!(Difficulty::parse(difficulty.as_str()), Some(difficulty));
+    }
+    for period in Period::ALL {
+        assert_eq!(serde_json::to_value(period).unwrap(), period.as_str());
+        assert_eq!(Period::parse(period.as_str()), Some(period));
+    }
+    for on_conflict in OnConflict::ALL {
+        assert_eq!(
+            serde_json::to_value(on_conflict).unwrap(),
+            on_conflict.as_str()
+        );
+        assert_eq!(OnConflict::parse(on_conflict.as_str()), Some(on_conflict));
+    }
+    assert_eq!(Mode::parse("Classic"), None);
+}
+
+#[test]
+fn old_bodies_get_defaults() {
+    let run: Run = serde_json::from_str(r#"{"name":"alice","score":120}"#).unwrap();
+    assert_eq!(
+        run,
+        Run {
+            level: 0,
+            duration_secs: 0,
+            client_version: "unknown".into(),
+            ..self::run(120)
+        }
+    );
+
+    let entry: Entry = serde_json::from_str(r#"{"name":"alice","score":120}"#).unwrap();
+    assert_eq!(entry.client_version, "unknown");
+    assert_eq!(
+        (entry.mode, entry.difficulty),
+        (Mode::Classic, Difficulty::Normal)
+    );
+}
+
+#[test]
+fn unknown_error_kinds_are_accepted() {
+    let body: ErrorBody =
+        serde_json::from_str(r#"{"error":"from_the_future","message":"Nope"}"#).unwrap();
+    assert_eq!(body.error, ErrorKind::Unknown);
+
+    let body: ErrorBody =
+        serde_json::from_str(r#"{"error":"not_found","message":"Nope"}"#).unwrap();
+    assert_eq!(body.error, ErrorKind::NotFound);
+}
+
+#[test]
+fn names_are_validated() {
+    assert_eq!(validation::name("alice_42-x"), Ok(()));
+    assert_eq!(validation::name(""), Err(NameError::Empty));
+    assert_eq!(
+        validation::name("a b"),
+        Err(NameError::InvalidCharacter(' '))
+    );
+    assert_eq!(
+        validation::name(&"a".repeat(validation::MAX_NAME_LEN + 1)),
+        Err(NameError::TooLong)
+    );
+    assert_eq!(validation::name("xX_ShIt_Xx"), Err(NameError::Profane));
+}
+
+#[test]
+fn runs_are_validated() {
+    assert_eq!(validation::run(&run(120)), Ok(()));
+    assert_eq!(
+        validation::run(&run(-10)),
+        Err(RunError::Score(ScoreError::Negative))
+    );
+    assert_eq!(
+        validation::run(&run(125)),
+        Err(RunError::Score(ScoreError::Unreachable))
+    );
+    assert_eq!(
+        validation::run(&Run {
+            level: -1,
+            ..run(120)
+        }),
+        Err(RunError::Negative)
+    );
+
+    let over_time = Run {
+        mode: Mode::TimeAttack,
+        duration_secs: 181,
+        ..run(120)
+    };
+    assert_eq!(validation::run(&over_time), Err(RunError::OverTime));
+    assert_eq!(
+        validation::run(&Run {
+            mode: Mode::Classic,
+            ..over_time
+        }),
+        Ok(())
+    );
+}
+
+#[test]
+fn old_backups_are_read() {
+    let run: ExportedRun = serde_json::from_str(
+        r#"{"name":"alice","score":120,"level":3,"duration_secs":120,"client_version":"0.1.0",
+            "verified":true,"hidden":false,"submitted_at":1700000000}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        (run.mode, run.difficulty),
+        (Mode::Classic, Difficulty::Normal)
+    );
+}
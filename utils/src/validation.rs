@@ -0,0 +1,152 @@
+use crate::{points, Mode, Run};
+use std::fmt;
+
+pub const MAX_NAME_LEN: usize = 16;
+/// Far beyond any legitimate Run
+pub const MAX_SCORE: i64 = 10_000_000;
+
+/// Words that may not appear anywhere in a Name
+const PROFANITY: [&str; 8] = [
+    "asshole", "bastard", "bitch", "cunt", "fuck", "nazi", "shit", "whore",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameError {
+    Empty,
+    TooLong,
+    InvalidCharacter(char),
+    Profane,
+}
+
+impl fmt::Display for NameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "The Name must not be empty"),
+            Self::TooLong => write!(f, "The Name must be at most {MAX_NAME_LEN} Characters long"),
+            Self::InvalidCharacter(c) => write!(f, "The Name must not contain '{c}'"),
+            Self::Profane => write!(f, "The Name contains a forbidden Word"),
+        }
+    }
+}
+
+impl std::error::Error for NameError {}
+
+/// Checks that a Name only consists of `A-Z`, `a-z`, `0-9`, `_` and `-`,
+/// is at most [`MAX_NAME_LEN`] Characters long and contains no Profanity.
+///
+/// # Errors
+///
+/// Returns the first Rule the Name violates.
+pub fn name(name: &str) -> Result<(), NameError> {
+    if name.is_empty() {
+        return Err(NameError::Empty);
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|&c| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+    {
+        return Err(NameError::InvalidCharacter(c));
+    }
+    if name.len() > MAX_NAME_LEN {
+        return Err(NameError::TooLong);
+    }
+
+    let normalized = name
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if PROFANITY.iter().any(|word| normalized.contains(word)) {
+        return Err(NameError::Profane);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreError {
+    Negative,
+    TooHigh,
+    Unreachable,
+}
+
+impl fmt::Display for ScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Negative => write!(f, "The Score must not be negative"),
+            Self::TooHigh => write!(f, "The Score must be at most {MAX_SCORE}"),
+            Self::Unreachable => write!(f, "The Score must be a Multiple of {}", points::STEP),
+        }
+    }
+}
+
+impl std::error::Error for ScoreError {}
+
+/// Checks that a Score could have been reached with the Game's Point Values
+///
+/// # Errors
+///
+/// Returns the first Rule the Score violates.
+pub const fn score(score: i64) -> Result<(), ScoreError> {
+    #[allow(clippy::cast_possible_wrap)]
+    const STEP: i64 = points::STEP as i64;
+
+    if score < 0 {
+        Err(ScoreError::Negative)
+    } else if score > MAX_SCORE {
+        Err(ScoreError::TooHigh)
+    } else if score % STEP != 0 {
+        Err(ScoreError::Unreachable)
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunError {
+    Score(ScoreError),
+    /// The Level or Duration is negative
+    Negative,
+    /// A Time Attack went on for longer than [`Mode::TIME_LIMIT_SECS`]
+    OverTime,
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Score(e) => write!(f, "{e}"),
+            Self::Negative => write!(f, "Level and Duration must not be negative"),
+            Self::OverTime => write!(
+                f,
+                "A Time Attack lasts at most {} Seconds",
+                Mode::TIME_LIMIT_SECS
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<ScoreError> for RunError {
+    fn from(value: ScoreError) -> Self {
+        Self::Score(value)
+    }
+}
+
+/// Checks the Score of the Run and that it fits its Mode
+///
+/// # Errors
+///
+/// Returns the first Rule the Run violates.
+pub fn run(run: &Run) -> Result<(), RunError> {
+    score(run.score)?;
+    if run.level < 0 || run.duration_secs < 0 {
+        return Err(RunError::Negative);
+    }
+    if run.mode == Mode::TimeAttack
+        && u64::try_from(run.duration_secs).is_ok_and(|secs| secs > Mode::TIME_LIMIT_SECS)
+    {
+        return Err(RunError::OverTime);
+    }
+    Ok(())
+}